@@ -0,0 +1,39 @@
+//!
+//! Example program that simply displays a right-to-left table of Hebrew and Arabic text, reordered for display.
+//!
+
+use tableau::*;
+
+fn main() {
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .with_direction(Direction::RightToLeft)
+        .with_bidi_reordering()
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("עיר"))
+                .with_cell(Cell::new("מדינה"))
+                .with_cell(Cell::new("אוכלוסייה").with_alignment(Alignment::End)),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::new("ירושלים"))
+                .with_cell(Cell::new("ישראל"))
+                .with_cell(Cell::new("971,800").with_alignment(Alignment::End)),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::new("القاهرة"))
+                .with_cell(Cell::new("مصر"))
+                .with_cell(Cell::new("10,100,166").with_alignment(Alignment::End)),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::new("Paris (פריז)"))
+                .with_cell(Cell::new("צרפת"))
+                .with_cell(Cell::new("2,102,650").with_alignment(Alignment::End)),
+        );
+
+    println!("{}", table.render());
+}
@@ -25,7 +25,7 @@ fn main() {
     let table =
         Table::new()
             .with_row(
-                Row::new().with_cell(
+                Row::new().with_kind(RowKind::Header).with_cell(
                     Cell::new("CITIES AROUND THE WORLD".bold())
                         .with_alignment(Alignment::Center)
                         .with_column_span(4),
@@ -33,6 +33,7 @@ fn main() {
             )
             .with_row(
                 Row::new()
+                    .with_kind(RowKind::Header)
                     .with_cell(Cell::new("CITY".bold()).with_alignment(Alignment::Center))
                     .with_cell(Cell::new("COUNTRY".bold()).with_alignment(Alignment::Center))
                     .with_cell(Cell::new("LONGITUDE".bold()).with_alignment(Alignment::Center))
@@ -0,0 +1,50 @@
+//!
+//! Example program that simply displays a table filling the whole width of the terminal, using column weights.
+//!
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let data = [
+        (
+            "deploy-frontend",
+            "Deploying version 2.14.0 to the production cluster",
+            "42%",
+        ),
+        (
+            "nightly-backup",
+            "Uploading database snapshot to cold storage",
+            "87%",
+        ),
+        (
+            "reindex-search",
+            "Waiting for the previous job to complete",
+            "0%",
+        ),
+    ];
+
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .without_separate_rows()
+        .with_terminal_width()
+        .with_column_grow_weight(0, 1)
+        .with_column_grow_weight(1, 3)
+        .with_column_shrink_weight(0, 0)
+        .with_column_shrink_weight(2, 0)
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("JOB".bold()))
+                .with_cell(Cell::new("STATUS".bold()))
+                .with_cell(Cell::new("PROGRESS".bold())),
+        )
+        .with_rows(data.iter().map(|(job, status, progress)| {
+            Row::new()
+                .with_cell(Cell::new(job))
+                .with_cell(Cell::new(status))
+                .with_cell(Cell::new(progress).with_alignment(Alignment::Right))
+        }));
+
+    println!("{}", table.render());
+}
@@ -0,0 +1,35 @@
+//!
+//! Example program that simply displays a table of prices, aligned on their decimal separators.
+//!
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let data = [
+        ("Coffee", "3.5", "2,75"),
+        ("Sandwich", "12.25", "9,9"),
+        ("Lunch menu", "100", "84,125"),
+        ("Water", "0.9", "0,5"),
+        ("Unavailable", "-", "-"),
+    ];
+
+    let table = Table::new()
+        .with_style(Style::thin())
+        .without_separate_rows()
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("ITEM".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("PRICE (USD)".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("PRICE (EUR)".bold()).with_alignment(Alignment::Center)),
+        )
+        .with_rows(data.iter().map(|(item, usd, eur)| {
+            Row::new()
+                .with_cell(Cell::new(item))
+                .with_cell(Cell::new(usd.green()).with_alignment(Alignment::Decimal('.')))
+                .with_cell(Cell::new(eur).with_alignment(Alignment::Decimal(',')))
+        }));
+
+    println!("{}", table.render());
+}
@@ -0,0 +1,26 @@
+//!
+//! Example program that simply displays a table of search results, without any result.
+//!
+
+use tableau::*;
+
+fn main() {
+    let results: Vec<(&str, &str)> = Vec::new();
+
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .with_placeholder("No results")
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("NAME"))
+                .with_cell(Cell::new("PATH")),
+        )
+        .with_rows(results.into_iter().map(|(name, path)| {
+            Row::new()
+                .with_cell(Cell::new(name))
+                .with_cell(Cell::new(path))
+        }));
+
+    println!("{}", table.render());
+}
@@ -0,0 +1,61 @@
+//!
+//! Example program that simply displays a wide table, which falls back to a list of records when it does not fit.
+//!
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let data = [
+        (
+            "i-0a1b2c3d",
+            "web-frontend-1",
+            "t3.large",
+            "eu-west-1a",
+            "10.0.1.12",
+            "running",
+        ),
+        (
+            "i-4e5f6a7b",
+            "web-frontend-2",
+            "t3.large",
+            "eu-west-1b",
+            "10.0.2.34",
+            "running",
+        ),
+        (
+            "i-8c9d0e1f",
+            "batch-worker-with-a-long-name",
+            "c6i.4xlarge",
+            "eu-west-1c",
+            "10.0.3.56",
+            "stopped",
+        ),
+    ];
+
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .with_max_width(60)
+        .with_expanded(Expanded::Auto)
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("INSTANCE".bold()))
+                .with_cell(Cell::new("NAME".bold()))
+                .with_cell(Cell::new("TYPE".bold()))
+                .with_cell(Cell::new("ZONE".bold()))
+                .with_cell(Cell::new("PRIVATE IP".bold()))
+                .with_cell(Cell::new("STATE".bold())),
+        )
+        .with_rows(data.iter().map(|(instance, name, kind, zone, ip, state)| {
+            Row::new()
+                .with_cell(Cell::new(instance))
+                .with_cell(Cell::new(name))
+                .with_cell(Cell::new(kind))
+                .with_cell(Cell::new(zone))
+                .with_cell(Cell::new(ip))
+                .with_cell(Cell::new(state))
+        }));
+
+    println!("{}", table.render());
+}
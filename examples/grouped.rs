@@ -0,0 +1,40 @@
+//!
+//! Example program that simply displays a table of cities grouped by country, with population subtotals.
+//!
+
+use tableau::*;
+
+fn main() {
+    let data = [
+        ("Osaka", "Japan", 2_753_862),
+        ("Lyon", "France", 522_250),
+        ("Tokyo", "Japan", 14_094_034),
+        ("Paris", "France", 2_102_650),
+        ("Marseille", "France", 873_076),
+        ("Toronto", "Canada", 2_794_356),
+        ("Kyoto", "Japan", 1_463_723),
+    ];
+
+    let mut table = Table::new()
+        .with_style(Style::rounded())
+        .without_separate_rows()
+        .with_group_subtotals()
+        .with_group_header_style(CellStyle::new().with_bold().with_foreground(Color::Cyan))
+        .with_column_formatter(2, ValueFormatter::new().with_thousands_separator(','))
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("CITY"))
+                .with_cell(Cell::new("COUNTRY"))
+                .with_cell(Cell::new("POPULATION")),
+        )
+        .with_rows(data.into_iter().map(|(city, country, population)| {
+            Row::new()
+                .with_cell(Cell::new(city))
+                .with_cell(Cell::new(country))
+                .with_cell(Cell::from_value(population).with_alignment(Alignment::Right))
+        }));
+    table.group_by_column("COUNTRY");
+
+    println!("{}", table.render());
+}
@@ -0,0 +1,38 @@
+//!
+//! Example program that simply displays multiple tables one after another, lined up using fixed column widths.
+//!
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let hours = [
+        ("09:00", vec![("login", 1204), ("checkout", 87)]),
+        (
+            "10:00",
+            vec![("login", 98), ("checkout", 1432), ("password-reset", 3)],
+        ),
+    ];
+
+    for (hour, events) in hours {
+        let table = Table::new()
+            .with_style(Style::rounded())
+            .without_separate_rows()
+            .with_fixed_column_width_at_index(0, 24)
+            .with_min_column_width_at_index(1, 10)
+            .with_row(
+                Row::new().with_kind(RowKind::Header).with_cell(
+                    Cell::new(format!("SUMMARY FOR {hour}").bold())
+                        .with_alignment(Alignment::Center)
+                        .with_column_span(2),
+                ),
+            )
+            .with_rows(events.into_iter().map(|(event, count)| {
+                Row::new()
+                    .with_cell(Cell::new(event))
+                    .with_cell(Cell::from_value(count).with_alignment(Alignment::Right))
+            }));
+
+        println!("{}", table.render());
+    }
+}
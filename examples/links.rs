@@ -0,0 +1,31 @@
+//!
+//! Example program that simply displays a table of clickable links, some of them wrapped over multiple lines.
+//!
+
+use tableau::*;
+
+fn main() {
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .with_max_column_width(24)
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("CRATE"))
+                .with_cell(Cell::new("DESCRIPTION")),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::new("regex").with_link("https://crates.io/crates/regex"))
+                .with_cell(Cell::new("Regular expressions for Rust, with guaranteed linear time matching")),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::new("unicode-width").with_link("https://crates.io/crates/unicode-width"))
+                .with_cell(Cell::new(
+                    "Display width of Unicode characters, see \x1b]8;;https://www.unicode.org/reports/tr11/\x1b\\UAX #11 (East Asian Width)\x1b]8;;\x1b\\",
+                )),
+        );
+
+    println!("{}", table.render());
+}
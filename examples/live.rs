@@ -0,0 +1,37 @@
+//!
+//! Example program that simply displays the progress of some downloads, redrawing the table in place as they progress.
+//!
+
+use std::thread;
+use std::time::Duration;
+
+use tableau::*;
+
+fn main() -> std::io::Result<()> {
+    let downloads = [("archive.tar.gz", 7), ("image.iso", 3), ("notes.txt", 25)];
+
+    let mut live_table = LiveTable::stdout();
+    for step in 0..=10 {
+        let mut table = Table::new().with_style(Style::rounded()).with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("FILE"))
+                .with_cell(Cell::new("PROGRESS").with_alignment(Alignment::Right)),
+        );
+        for (index, (name, speed)) in downloads.iter().enumerate() {
+            let progress = (step * speed).min(100);
+            let row = Row::new()
+                .with_cell(Cell::new(name))
+                .with_cell(Cell::new(format!("{progress}%")).with_alignment(Alignment::Right));
+            table = table.with_row(if index == 0 {
+                row
+            } else {
+                row.without_top_border()
+            });
+        }
+        live_table.update(&table)?;
+        thread::sleep(Duration::from_millis(200));
+    }
+
+    Ok(())
+}
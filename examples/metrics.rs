@@ -0,0 +1,55 @@
+//!
+//! Example program that simply displays a table of metrics, colored using conditional formatting rules.
+//!
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let data = [
+        ("GET /users", "1204", "87ms", "0.2%"),
+        ("GET /users/:id", "9841", "12ms", "0.0%"),
+        ("POST /orders", "312", "643ms", "3.1%"),
+        ("GET /orders/:id", "2210", "45ms", "0.4%"),
+        ("DELETE /sessions", "77", "502ms", "0.0%"),
+        ("GET /health", "14002", "1ms", "0.0%"),
+    ];
+
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .without_separate_rows()
+        .with_column_rule(1, Rule::Heatmap((30, 30, 30), (0, 95, 135)))
+        .with_column_rule(
+            2,
+            Rule::GreaterThan(500.0, CellStyle::new().with_foreground(Color::Red)),
+        )
+        .with_column_rule(2, Rule::Maximum(CellStyle::new().with_bold()))
+        .with_column_rule(
+            0,
+            Rule::predicate(
+                |cell| cell.content.starts_with("DELETE"),
+                CellStyle::new().with_italic(),
+            ),
+        )
+        .with_column_rule(
+            3,
+            Rule::GreaterThan(0.0, CellStyle::new().with_foreground(Color::Yellow)),
+        )
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("ENDPOINT".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("REQUESTS".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("LATENCY".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("ERRORS".bold()).with_alignment(Alignment::Center)),
+        )
+        .with_rows(data.iter().map(|(endpoint, requests, latency, errors)| {
+            Row::new()
+                .with_cell(Cell::new(endpoint))
+                .with_cell(Cell::new(requests).with_alignment(Alignment::Right))
+                .with_cell(Cell::new(latency).with_alignment(Alignment::Right))
+                .with_cell(Cell::new(errors).with_alignment(Alignment::Right))
+        }));
+
+    println!("{}", table.render());
+}
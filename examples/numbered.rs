@@ -0,0 +1,54 @@
+//!
+//! Example program that simply displays a table of tasks grouped by status, with numbered rows.
+//!
+
+use tableau::*;
+
+fn main() {
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .without_separate_rows()
+        .with_row_numbers(RowNumbering::default())
+        .with_row(
+            Row::new().with_kind(RowKind::Header).with_cell(
+                Cell::new("TASKS")
+                    .with_alignment(Alignment::Center)
+                    .with_column_span(2),
+            ),
+        )
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("NAME"))
+                .with_cell(Cell::new("OWNER")),
+        )
+        .with_group(
+            "In progress",
+            [
+                Row::new()
+                    .with_cell(Cell::new("Rewrite the parser"))
+                    .with_cell(Cell::new("alice")),
+                Row::new()
+                    .with_cell(Cell::new("Update dependencies"))
+                    .with_cell(Cell::new("bob")),
+            ],
+        )
+        .with_group(
+            "Done",
+            [
+                Row::new()
+                    .with_cell(Cell::new("Release 1.2.0"))
+                    .with_cell(Cell::new("carol")),
+                Row::new().with_cell(Cell::new("Fix flaky tests").with_column_span(2)),
+            ],
+        )
+        .with_row(
+            Row::new().with_kind(RowKind::Footer).with_cell(
+                Cell::new("4 tasks")
+                    .with_alignment(Alignment::Right)
+                    .with_column_span(2),
+            ),
+        );
+
+    println!("{}", table.render());
+}
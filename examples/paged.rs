@@ -0,0 +1,26 @@
+//!
+//! Example program that simply displays a long table of numbers, through a pager if it does not fit in the terminal.
+//!
+
+use tableau::*;
+
+fn main() -> std::io::Result<()> {
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("N"))
+                .with_cell(Cell::new("SQUARE"))
+                .with_cell(Cell::new("CUBE")),
+        )
+        .with_rows((1..=100i64).map(|n| {
+            Row::new()
+                .without_top_border()
+                .with_cell(Cell::from_value(n))
+                .with_cell(Cell::from_value(n * n))
+                .with_cell(Cell::from_value(n * n * n))
+        }));
+
+    table.print_paged()
+}
@@ -0,0 +1,53 @@
+//!
+//! Example program that simply displays a table of planets, split into pages with their header repeated.
+//!
+
+use tableau::*;
+
+fn main() {
+    let data = [
+        ("Mercury", "0.39", "88"),
+        ("Venus", "0.72", "225"),
+        ("Earth", "1.00", "365"),
+        ("Mars", "1.52", "687"),
+        ("Jupiter", "5.20", "4333"),
+        ("Saturn", "9.58", "10759"),
+        ("Uranus", "19.22", "30687"),
+        ("Neptune", "30.05", "60190"),
+    ];
+
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .with_page_numbers()
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("PLANET"))
+                .with_cell(Cell::new("DISTANCE (AU)"))
+                .with_cell(Cell::new("ORBIT (DAYS)")),
+        )
+        .with_rows(
+            data.into_iter()
+                .enumerate()
+                .map(|(index, (planet, distance, orbit))| {
+                    let row = Row::new()
+                        .with_cell(Cell::new(planet))
+                        .with_cell(Cell::new(distance).with_alignment(Alignment::Decimal('.')))
+                        .with_cell(Cell::new(orbit).with_alignment(Alignment::Right));
+                    if index == 0 {
+                        row
+                    } else {
+                        row.without_top_border()
+                    }
+                }),
+        );
+
+    for page in table.paginate(3) {
+        println!("{page}\n");
+    }
+
+    println!("Pages of at most 7 lines:\n");
+    for page in table.paginate_by_height(7) {
+        println!("{page}\n");
+    }
+}
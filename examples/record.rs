@@ -0,0 +1,31 @@
+//!
+//! Example program that simply displays a single record as a transposed "key | value" table.
+//!
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let table = Table::new()
+        .with_style(Style::thin())
+        .without_separate_rows()
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("ID".bold()))
+                .with_cell(Cell::new("NAME".bold()))
+                .with_cell(Cell::new("EMAIL".bold()))
+                .with_cell(Cell::new("ROLE".bold()))
+                .with_cell(Cell::new("LAST LOGIN".bold())),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::from_value(1042))
+                .with_cell(Cell::new("Ada Lovelace"))
+                .with_cell(Cell::new("ada@example.com"))
+                .with_cell(Cell::new("admin"))
+                .with_cell(Cell::new("2024-06-01T12:30:00Z")),
+        );
+
+    println!("{}", table.transpose().render());
+}
@@ -0,0 +1,39 @@
+//!
+//! Example program that simply displays a wide table of monthly sales, sliced to fit in 60 columns with its first column frozen.
+//!
+
+use tableau::*;
+
+fn main() {
+    let months = [
+        "JAN", "FEB", "MAR", "APR", "MAY", "JUN", "JUL", "AUG", "SEP", "OCT", "NOV", "DEC",
+    ];
+    let products = [("Keyboards", 1200), ("Mice", 860), ("Monitors", 4310)];
+
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .with_max_width(60)
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("PRODUCT"))
+                .with_cells(
+                    months
+                        .iter()
+                        .map(|month| Cell::new(month).with_alignment(Alignment::Right)),
+                ),
+        )
+        .with_rows(products.iter().enumerate().map(|(index, (product, base))| {
+            let row = if index == 0 {
+                Row::new()
+            } else {
+                Row::new().without_top_border()
+            };
+            row.with_cell(Cell::new(product))
+                .with_cells((0..months.len() as i64).map(|month| {
+                    Cell::from_value(base + month * 37 % 500).with_alignment(Alignment::Right)
+                }))
+        }));
+
+    println!("{}", table.render_sliced(1));
+}
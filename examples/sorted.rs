@@ -0,0 +1,42 @@
+//!
+//! Example program that simply displays a table of files, sorted by kind and then naturally by name.
+//!
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let data = [
+        ("file10.txt", "text", 2_048),
+        ("file2.txt", "text", 512),
+        ("image1.png", "image", 84_120),
+        ("File1.txt", "text", 1_024),
+        ("image12.png", "image", 4_096),
+        ("image3.png", "image", 120_402),
+        ("file1.txt", "text", 128),
+    ];
+
+    let mut table = Table::new()
+        .with_style(Style::rounded())
+        .without_separate_rows()
+        .with_column_formatter(2, ValueFormatter::new().with_human_readable_bytes())
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("NAME".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("KIND".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("SIZE".bold()).with_alignment(Alignment::Center)),
+        )
+        .with_rows(data.iter().map(|(name, kind, size)| {
+            Row::new()
+                .with_cell(Cell::new(name))
+                .with_cell(Cell::new(kind))
+                .with_cell(Cell::from_value(Value::Bytes(*size)).with_alignment(Alignment::Right))
+        }));
+
+    table.sort_by_columns(&[(1, Order::Ascending), (0, Order::Ascending)]);
+    println!("{}", table.render());
+
+    table.sort_by_column(2, Order::Descending);
+    println!("{}", table.render());
+}
@@ -0,0 +1,49 @@
+//!
+//! Example program that simply streams log lines into a table, writing each row as soon as it arrives.
+//!
+
+use std::io;
+use std::thread;
+use std::time::Duration;
+
+use tableau::*;
+
+fn main() -> io::Result<()> {
+    let template = Table::new().with_style(Style::rounded()).with_row(
+        Row::new()
+            .with_kind(RowKind::Header)
+            .with_cell(Cell::new("TIME"))
+            .with_cell(Cell::new("LEVEL").with_alignment(Alignment::Center))
+            .with_cell(Cell::new("MESSAGE")),
+    );
+
+    let lines = [
+        ("12:00:01", "INFO", "Server started on port 8080"),
+        ("12:00:04", "DEBUG", "Loaded 42 routes"),
+        (
+            "12:00:09",
+            "WARN",
+            "Slow query took 1532ms, consider adding an index on users.email",
+        ),
+        ("12:00:12", "INFO", "GET /health 200"),
+        ("12:00:15", "ERROR", "Connection to cache lost"),
+    ];
+
+    let column_widths = ColumnWidths::Fixed(vec![10, 7, 40]);
+    let mut table = StreamingTable::new(template, column_widths, io::stdout())?;
+    for (index, (time, level, message)) in lines.into_iter().enumerate() {
+        thread::sleep(Duration::from_millis(300));
+        // Only the first row is separated from the header.
+        let row = if index == 0 {
+            Row::new()
+        } else {
+            Row::new().without_top_border()
+        };
+        table.push_row(
+            row.with_cell(Cell::new(time))
+                .with_cell(Cell::new(level).with_alignment(Alignment::Center))
+                .with_cell(Cell::new(message)),
+        )?;
+    }
+    table.finish()
+}
@@ -0,0 +1,44 @@
+//!
+//! Example program that simply displays a table with zebra-striped rows, and a highlighted row.
+//!
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let data = [
+        ("api-gateway", "running", "3/3"),
+        ("auth-service", "running", "2/2"),
+        ("billing-worker", "degraded", "1/3"),
+        ("notifications", "running", "2/2"),
+        ("search-indexer", "running", "4/4"),
+        ("report-builder", "stopped", "0/1"),
+    ];
+
+    let table = Table::new()
+        .with_style(Style::thin())
+        .without_separate_rows()
+        .with_zebra_stripes(Color::Ansi(236), Color::Ansi(238))
+        .with_row_styler(|_, row| {
+            if row.cells.iter().any(|cell| cell.content == "degraded") {
+                CellStyle::new().with_foreground(Color::Yellow).with_bold()
+            } else {
+                CellStyle::new()
+            }
+        })
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("SERVICE".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("STATUS".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("REPLICAS".bold()).with_alignment(Alignment::Center)),
+        )
+        .with_rows(data.iter().map(|(service, status, replicas)| {
+            Row::new()
+                .with_cell(Cell::new(service))
+                .with_cell(Cell::new(status).with_alignment(Alignment::Center))
+                .with_cell(Cell::new(replicas).with_alignment(Alignment::Right))
+        }));
+
+    println!("{}", table.render());
+}
@@ -0,0 +1,57 @@
+//!
+//! Example program that simply displays an invoice, with a footer row computed from the typed values of its columns.
+//!
+
+use std::time::Duration;
+
+use tableau::*;
+
+fn main() {
+    let data = [
+        ("Design review", "alice", Duration::from_secs(5400), 12000),
+        (
+            "Database migration",
+            "bob",
+            Duration::from_secs(10800),
+            34050,
+        ),
+        (
+            "Hotfix deployment",
+            "alice",
+            Duration::from_secs(1800),
+            4500,
+        ),
+        ("Load testing", "carol", Duration::from_secs(7200), 21075),
+    ];
+
+    let table =
+        Table::new()
+            .with_style(Style::rounded())
+            .with_column_formatter(3, ValueFormatter::new().with_thousands_separator(','))
+            .with_column_aggregation(1, Aggregation::DistinctCount)
+            .with_column_aggregation(2, Aggregation::Sum)
+            .with_column_aggregation(3, Aggregation::Sum)
+            .with_row(
+                Row::new()
+                    .with_kind(RowKind::Header)
+                    .with_cell(Cell::new("TASK"))
+                    .with_cell(Cell::new("ASSIGNEES"))
+                    .with_cell(Cell::new("TIME SPENT"))
+                    .with_cell(Cell::new("AMOUNT (¢)")),
+            )
+            .with_rows(data.into_iter().enumerate().map(
+                |(index, (task, assignee, time, amount))| {
+                    let row = if index == 0 {
+                        Row::new()
+                    } else {
+                        Row::new().without_top_border()
+                    };
+                    row.with_cell(Cell::new(task))
+                        .with_cell(Cell::new(assignee))
+                        .with_cell(Cell::from_value(time).with_alignment(Alignment::Right))
+                        .with_cell(Cell::from_value(amount).with_alignment(Alignment::Right))
+                },
+            ));
+
+    println!("{}", table.render());
+}
@@ -0,0 +1,56 @@
+//!
+//! Example program that simply displays a table of typed values, formatted per column.
+//!
+
+use std::time::{Duration, SystemTime};
+
+use owo_colors::OwoColorize;
+use tableau::*;
+
+fn main() {
+    let now = SystemTime::now();
+    let data = [
+        (
+            "backup.tar.gz",
+            4_831_838_208,
+            3 * 86400,
+            false,
+            Some(1_204_332),
+        ),
+        ("notes.md", 2_048, 300, false, Some(87)),
+        ("deploy.sh", 912, 7200, true, Some(31)),
+        ("photo.raw", 25_165_824, 45 * 86400, false, None),
+    ];
+
+    let table = Table::new()
+        .with_style(Style::rounded())
+        .without_separate_rows()
+        .with_column_formatter(1, ValueFormatter::new().with_human_readable_bytes())
+        .with_column_formatter(2, ValueFormatter::new().with_relative_times())
+        .with_column_formatter(3, ValueFormatter::new().with_check_marks())
+        .with_column_formatter(
+            4,
+            ValueFormatter::new()
+                .with_thousands_separator(',')
+                .with_null_display("n/a"),
+        )
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("NAME".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("SIZE".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("MODIFIED".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("EXEC".bold()).with_alignment(Alignment::Center))
+                .with_cell(Cell::new("LINES".bold()).with_alignment(Alignment::Center)),
+        )
+        .with_rows(data.iter().map(|(name, size, age, is_executable, lines)| {
+            Row::new()
+                .with_cell(Cell::new(name))
+                .with_cell(Cell::from_value(Value::Bytes(*size)).with_alignment(Alignment::Right))
+                .with_cell(Cell::from_value(now - Duration::from_secs(*age)))
+                .with_cell(Cell::from_value(*is_executable).with_alignment(Alignment::Center))
+                .with_cell(Cell::from_value(*lines).with_alignment(Alignment::Right))
+        }));
+
+    println!("{}", table.render());
+}
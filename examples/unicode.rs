@@ -0,0 +1,34 @@
+//!
+//! Example program that simply displays a narrow table of wide and composed characters, wrapped without splitting them.
+//!
+
+use tableau::*;
+
+fn main() {
+    let table = Table::new()
+        .with_style(Style::thin())
+        .with_max_column_width(9)
+        .with_row(
+            Row::new()
+                .with_kind(RowKind::Header)
+                .with_cell(Cell::new("KIND"))
+                .with_cell(Cell::new("TEXT")),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::new("CJK"))
+                .with_cell(Cell::new("東京都渋谷区神南")),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::new("Emoji"))
+                .with_cell(Cell::new("👩‍👩‍👧‍👦🏳️‍🌈🇫🇷🇯🇵👍🏽")),
+        )
+        .with_row(
+            Row::new()
+                .with_cell(Cell::new("Marks"))
+                .with_cell(Cell::new("e\u{301}le\u{300}ve a\u{308}h")),
+        );
+
+    println!("{}", table.render());
+}
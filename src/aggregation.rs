@@ -0,0 +1,179 @@
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+
+use crate::cell::{Alignment, Cell};
+use crate::row::{Row, RowKind};
+use crate::value::Value;
+
+type CustomAggregationFn = dyn Fn(&[Value]) -> Value + Send + Sync;
+
+/// A custom aggregation over the typed values of a column.
+#[derive(Clone)]
+pub struct CustomAggregation(Arc<CustomAggregationFn>);
+
+impl CustomAggregation {
+    /// Creates a new custom aggregation from the given function.
+    pub fn new(aggregation: impl Fn(&[Value]) -> Value + Send + Sync + 'static) -> Self {
+        Self(Arc::new(aggregation))
+    }
+
+    /// Aggregates the given values.
+    pub fn aggregate(&self, values: &[Value]) -> Value {
+        (self.0)(values)
+    }
+}
+
+impl fmt::Debug for CustomAggregation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CustomAggregation(..)")
+    }
+}
+
+impl PartialEq for CustomAggregation {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// An aggregation of the cells of a column, computed when rendering to summarize the column in a footer row.
+///
+/// Aggregations only consider the typed values of cells (nulls excluded), except for counts, which consider every non-empty cell.
+/// Cells spanning multiple columns are never aggregated.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Aggregation {
+    /// The sum of the values, if they can all be added together (like numbers, durations or sizes).
+    Sum,
+    /// The mean of the values, if they can all be added together.
+    ///
+    /// Means of integers and decimals are floats.
+    Mean,
+    /// The minimum value.
+    Min,
+    /// The maximum value.
+    Max,
+    /// The number of non-empty cells.
+    Count,
+    /// The number of distinct non-empty cells, compared by their contents or values.
+    DistinctCount,
+    /// A custom aggregation of the values.
+    Custom(CustomAggregation),
+}
+
+impl Aggregation {
+    /// Creates a custom aggregation of the values.
+    pub fn custom(aggregation: impl Fn(&[Value]) -> Value + Send + Sync + 'static) -> Self {
+        Aggregation::Custom(CustomAggregation::new(aggregation))
+    }
+
+    /// Aggregates the given cells, returning `None` if they cannot be aggregated.
+    pub(crate) fn aggregate(&self, cells: &[&Cell]) -> Option<Value> {
+        let values: Vec<Value> = cells
+            .iter()
+            .filter(|cell| cell.column_span == 1)
+            .filter_map(|cell| cell.value)
+            .filter(|value| *value != Value::Null)
+            .collect();
+        let compare = |a: &&Value, b: &&Value| {
+            let (a, b) = (a.as_f64(), b.as_f64());
+            a.partial_cmp(&b).unwrap_or(std::cmp::Ordering::Equal)
+        };
+        let contents = || {
+            cells
+                .iter()
+                .filter(|cell| cell.column_span == 1)
+                .map(|cell| match cell.value {
+                    Some(value) => value.to_string(),
+                    None => cell.content.clone(),
+                })
+                .filter(|content| !content.is_empty())
+        };
+
+        match self {
+            Aggregation::Sum => sum(&values),
+            Aggregation::Mean => mean(&values),
+            Aggregation::Min => values
+                .iter()
+                .filter(|value| value.as_f64().is_some())
+                .min_by(compare)
+                .copied(),
+            Aggregation::Max => values
+                .iter()
+                .filter(|value| value.as_f64().is_some())
+                .max_by(compare)
+                .copied(),
+            Aggregation::Count => Some(Value::Integer(contents().count() as i64)),
+            Aggregation::DistinctCount => Some(Value::Integer(
+                contents().collect::<HashSet<_>>().len() as i64,
+            )),
+            Aggregation::Custom(aggregation) => Some(aggregation.aggregate(&values)),
+        }
+    }
+}
+
+/// Builds a footer row aggregating the cells of the given rows, each column with its aggregation from `aggregation_at`, if any.
+///
+/// Columns without an aggregation (or which cannot be aggregated) are left empty, except for the first one, holding the label.
+/// Aggregated cells are aligned like the last cell of their column.
+pub(crate) fn aggregate_rows<'a>(
+    rows: &[Row],
+    column_count: usize,
+    aggregation_at: impl Fn(usize) -> Option<&'a Aggregation>,
+    label: &str,
+) -> Row {
+    let mut is_labelled = false;
+    let cells: Vec<Cell> = (0..column_count)
+        .map(|column_index| {
+            let cells: Vec<&Cell> = rows
+                .iter()
+                .filter(|row| row.kind == RowKind::Body)
+                .filter_map(|row| row.cell_at_column(column_index))
+                .collect();
+            let value =
+                aggregation_at(column_index).and_then(|aggregation| aggregation.aggregate(&cells));
+            match value {
+                Some(value) => {
+                    let alignment = cells.last().map_or(Alignment::Right, |cell| cell.alignment);
+                    Cell::from_value(value).with_alignment(alignment)
+                }
+                None if !is_labelled => {
+                    is_labelled = true;
+                    Cell::new(label)
+                }
+                None => Cell::new(""),
+            }
+        })
+        .collect();
+    Row::new().with_kind(RowKind::Footer).with_cells(cells)
+}
+
+/// Returns the sum of the given values, if there are any and they can all be added together.
+fn sum(values: &[Value]) -> Option<Value> {
+    let is_addable = |value: &Value| {
+        matches!(
+            value,
+            Value::Integer(_)
+                | Value::Float(_)
+                | Value::Decimal(..)
+                | Value::Duration(_)
+                | Value::Bytes(_)
+        )
+    };
+    if !values.iter().all(is_addable) {
+        return None;
+    }
+    let (first, others) = values.split_first()?;
+    others
+        .iter()
+        .try_fold(*first, |sum, value| sum.checked_add(*value))
+}
+
+/// Returns the mean of the given values, if there are any and they can all be added together.
+fn mean(values: &[Value]) -> Option<Value> {
+    let count = values.len();
+    match sum(values)? {
+        Value::Duration(duration) => Some(Value::Duration(duration.div_f64(count as f64))),
+        Value::Bytes(bytes) => Some(Value::Bytes((bytes as f64 / count as f64).round() as u64)),
+        value => Some(Value::Float(value.as_f64()? / count as f64)),
+    }
+}
@@ -0,0 +1,23 @@
+use std::borrow::Cow;
+
+use unicode_bidi::ParagraphBidiInfo;
+
+/// The direction of a table, deciding the order of its columns and the meaning of start and end alignments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    /// The first column is on the left, and start-aligned content is aligned to the left.
+    LeftToRight,
+    /// The first column is on the right, and start-aligned content is aligned to the right.
+    RightToLeft,
+}
+
+/// Reorders a line from logical order to display order, following the Unicode Bidirectional Algorithm.
+///
+/// The base direction of the line is taken from its first strong character, and defaults to left-to-right.
+/// Lines containing ANSI sequences are returned as-is, since reordering them would scramble the sequences.
+pub(crate) fn reorder_line(line: &str) -> Cow<'_, str> {
+    if crate::ANSI_REGEX.is_match(line) {
+        return Cow::Borrowed(line);
+    }
+    ParagraphBidiInfo::new(line, None).reorder_line(0..line.len())
+}
@@ -1,4 +1,9 @@
-use std::collections::HashSet;
+use std::borrow::Cow;
+
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
+
+use crate::value::Value;
 
 /// The alignment of a cell's content.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -9,6 +14,15 @@ pub enum Alignment {
     Center,
     /// Align the content to the right.
     Right,
+    /// Align the content to the start of the table's direction (the left for left-to-right tables).
+    Start,
+    /// Align the content to the end of the table's direction (the right for left-to-right tables).
+    End,
+    /// Align the content on the given decimal separator, with the other cells of the same column using this alignment.
+    ///
+    /// The aligned numbers are then placed to the right of the column.
+    /// Cells spanning multiple columns are simply aligned to the right.
+    Decimal(char),
 }
 
 /// A cell in a table row.
@@ -19,9 +33,19 @@ pub struct Cell {
     /// The number of columns the cell spans.
     pub column_span: usize,
     /// The alignment of the cell's content.
+    ///
+    /// Cells are aligned to the start of the table's direction by default.
     pub alignment: Alignment,
     /// Whether the cell's content should be horizontally padded.
     pub has_padding: bool,
+    /// The URL the cell links to, if any.
+    ///
+    /// Links are rendered as OSC 8 hyperlinks, which are clickable in supporting terminals.
+    pub link: Option<String>,
+    /// The typed value of the cell, if any.
+    ///
+    /// When set, the content of the cell is replaced by the formatted value when rendering.
+    pub value: Option<Value>,
 }
 
 impl Cell {
@@ -30,8 +54,19 @@ impl Cell {
         Cell {
             content: content.to_string(),
             column_span: 1,
-            alignment: Alignment::Left,
+            alignment: Alignment::Start,
             has_padding: true,
+            link: None,
+            value: None,
+        }
+    }
+
+    /// Creates a new cell holding the given typed value.
+    pub fn from_value(value: impl Into<Value>) -> Self {
+        let value = value.into();
+        Cell {
+            value: Some(value),
+            ..Cell::new(value)
         }
     }
 
@@ -47,42 +82,329 @@ impl Cell {
         self
     }
 
+    /// Makes the cell link to the given URL.
+    pub fn with_link(mut self, url: impl ToString) -> Self {
+        self.link = Some(url.to_string());
+        self
+    }
+
     /// Disables horizontal padding for the cell.
     pub fn without_padding(mut self) -> Self {
         self.has_padding = false;
         self
     }
 
+    /// Returns the numeric value of the cell, if any.
+    ///
+    /// For cells holding a typed value, this is the numeric representation of that value.
+    /// Otherwise, this is the number making up the cell's content (ignoring ANSI sequences and surrounding whitespace),
+    /// optionally followed by a unit of at most 3 letters or `%` (like `12ms`, `5 GiB` or `0.2%`).
+    /// Digits of the integral part may be grouped by three with `,` or `_` (like `1,234,567`).
+    pub fn numeric_value(&self) -> Option<f64> {
+        if let Some(value) = self.value {
+            return value.as_f64();
+        }
+        let content = crate::ANSI_REGEX.replace_all(&self.content, "");
+        parse_number(content.trim())
+    }
+
+    /// Returns the widths of the integral and fractional parts (separator included) of each line of the cell.
+    ///
+    /// Returns `None` if the cell is not aligned on a decimal separator.
+    pub(crate) fn decimal_widths(&self) -> Option<(usize, usize)> {
+        let Alignment::Decimal(separator) = self.alignment else {
+            return None;
+        };
+        self.content
+            .lines()
+            .map(|line| {
+                let (integral, fractional) = split_decimal(line, separator);
+                (
+                    crate::visible_width(integral),
+                    crate::visible_width(fractional),
+                )
+            })
+            .reduce(|(a, b), (c, d)| (a.max(c), b.max(d)))
+    }
+
     /// Returns the width of the cell.
     pub(crate) fn width(&self) -> usize {
         crate::visible_width(&self.content) + if self.has_padding { 2 } else { 0 }
     }
 
+    /// Returns the given line of the cell's content, wrapped in a hyperlink to the cell's link, if any.
+    pub(crate) fn linked_line<'a>(&self, line: &'a str) -> Cow<'a, str> {
+        match self.link.as_ref() {
+            Some(link) if !line.is_empty() => {
+                Cow::Owned(format!("\x1b]8;;{link}\x1b\\{line}{HYPERLINK_END}"))
+            }
+            _ => Cow::Borrowed(line),
+        }
+    }
+
     /// Returns the contents of the cell, wrapped to fit in `max_width`.
+    ///
+    /// Lines are only broken between extended grapheme clusters (so emoji sequences, flags and combining marks are kept whole),
+    /// and never exceed `max_width`: a cluster too wide to ever fit is replaced by an ellipsis.
+    /// Lines are at least a single column wide, even if `max_width` is zero.
+    /// Hyperlinks spanning multiple lines are closed at the end of each line, and reopened on the next one.
     pub(crate) fn wrapped_content(&self, max_width: usize) -> Vec<String> {
-        let hidden: HashSet<usize> = crate::ANSI_REGEX
-            .find_iter(&self.content)
-            .flat_map(|m| m.start()..m.end())
-            .collect();
+        let max_width = max_width.max(1);
         let mut res: Vec<String> = Vec::new();
+        let mut hyperlink: Option<&str> = None;
         let mut buf = String::new();
-        let mut byte_index = 0;
-        for c in self.content.chars() {
-            if !hidden.contains(&byte_index)
-                && (crate::visible_width(&buf) >= max_width || c == '\n')
-            {
-                res.push(buf);
-                buf = String::new();
-                if c == '\n' {
-                    byte_index += 1;
-                    continue;
+        let mut buf_width = 0;
+
+        let mut end_line = |buf: &mut String, hyperlink: Option<&str>| {
+            let mut line = std::mem::take(buf);
+            if let Some(hyperlink) = hyperlink {
+                line.push_str(HYPERLINK_END);
+                buf.push_str(hyperlink);
+            }
+            res.push(line);
+        };
+
+        for (line_index, line) in self.content.split('\n').enumerate() {
+            if line_index > 0 {
+                end_line(&mut buf, hyperlink);
+                buf_width = 0;
+            }
+            let mut last_end = 0;
+            let escapes = crate::ANSI_REGEX
+                .find_iter(line)
+                .map(|m| (m.start(), m.end()));
+            for (start, end) in escapes.chain(std::iter::once((line.len(), line.len()))) {
+                for grapheme in line[last_end..start].graphemes(true) {
+                    let (grapheme, width) = match grapheme.width() {
+                        width if width > max_width => ("…", 1),
+                        width => (grapheme, width),
+                    };
+                    if buf_width + width > max_width && buf_width > 0 {
+                        end_line(&mut buf, hyperlink);
+                        buf_width = 0;
+                    }
+                    buf.push_str(grapheme);
+                    buf_width += width;
                 }
+                let escape = &line[start..end];
+                if let Some(params) = escape.strip_prefix("\x1b]8;") {
+                    let is_closing = params.split_once(';').is_none_or(|(_, uri)| {
+                        uri.trim_end_matches(['\x07', '\x1b', '\\']).is_empty()
+                    });
+                    hyperlink = if is_closing { None } else { Some(escape) };
+                }
+                buf.push_str(escape);
+                last_end = end;
             }
-            byte_index += c.len_utf8();
-            buf.push(c);
         }
         res.push(buf);
 
         res
     }
 }
+
+/// Parses a number, optionally followed by a unit, as described in [`Cell::numeric_value`].
+fn parse_number(content: &str) -> Option<f64> {
+    let unsigned = content.strip_prefix(['+', '-']).unwrap_or(content);
+    let sign = &content[..content.len() - unsigned.len()];
+
+    let integral_end = unsigned
+        .find(|c: char| !c.is_ascii_digit() && c != ',' && c != '_')
+        .unwrap_or(unsigned.len());
+    let (integral, rest) = unsigned.split_at(integral_end);
+    let (fractional, unit) = match rest.strip_prefix('.') {
+        Some(rest) => rest.split_at(
+            rest.find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len()),
+        ),
+        None => ("", rest),
+    };
+
+    let mut groups = integral.split([',', '_']);
+    let first_group = groups.next().unwrap_or_default();
+    let has_separators = integral.len() > first_group.len();
+    let is_grouped = !has_separators
+        || (!first_group.is_empty()
+            && first_group.len() <= 3
+            && groups.all(|group| group.len() == 3));
+    let unit = unit.strip_prefix(' ').unwrap_or(unit);
+    let is_unit = unit.chars().count() <= 3 && unit.chars().all(|c| c.is_alphabetic() || c == '%');
+    if !is_grouped || !is_unit || (integral.is_empty() && fractional.is_empty()) {
+        return None;
+    }
+
+    let digits = integral.replace([',', '_'], "");
+    format!("{sign}{digits}.{fractional}").parse().ok()
+}
+
+/// The sequence closing an OSC 8 hyperlink.
+const HYPERLINK_END: &str = "\x1b]8;;\x1b\\";
+
+/// Splits the given line into its integral and fractional parts, the separator being kept in the fractional part.
+///
+/// Separators found within ANSI sequences (like the URL of a hyperlink) are ignored.
+pub(crate) fn split_decimal(line: &str, separator: char) -> (&str, &str) {
+    let escapes: Vec<_> = crate::ANSI_REGEX
+        .find_iter(line)
+        .map(|m| m.range())
+        .collect();
+    let position = line
+        .match_indices(separator)
+        .map(|(index, _)| index)
+        .find(|index| !escapes.iter().any(|range| range.contains(index)));
+    line.split_at(position.unwrap_or(line.len()))
+}
+
+/// Expands tabs to spaces (up to the next multiple of `tab_width`) and replaces other control characters in the given content.
+///
+/// Carriage returns followed by newlines are removed, and ANSI sequences are left untouched.
+pub(crate) fn sanitize(content: &str, tab_width: usize, replacement: Option<char>) -> String {
+    let is_sanitized = |c: char| c.is_control() && c != '\n';
+    if !content.contains(is_sanitized) {
+        return content.to_string();
+    }
+
+    let content = content.replace("\r\n", "\n");
+    let mut sanitized = String::with_capacity(content.len());
+    for (line_index, line) in content.split('\n').enumerate() {
+        if line_index > 0 {
+            sanitized.push('\n');
+        }
+        let mut column = 0;
+        let mut last_end = 0;
+        let escapes = crate::ANSI_REGEX
+            .find_iter(line)
+            .map(|m| (m.start(), m.end()));
+        for (start, end) in escapes.chain(std::iter::once((line.len(), line.len()))) {
+            for grapheme in line[last_end..start].graphemes(true) {
+                if grapheme == "\t" {
+                    let spaces = if tab_width == 0 {
+                        0
+                    } else {
+                        tab_width - column % tab_width
+                    };
+                    sanitized.extend(std::iter::repeat_n(' ', spaces));
+                    column += spaces;
+                } else if grapheme.starts_with(is_sanitized) {
+                    if let Some(replacement) = replacement {
+                        sanitized.push(replacement);
+                        column += replacement.to_string().width();
+                    }
+                } else {
+                    sanitized.push_str(grapheme);
+                    column += grapheme.width();
+                }
+            }
+            sanitized.push_str(&line[start..end]);
+            last_end = end;
+        }
+    }
+    sanitized
+}
+
+#[cfg(test)]
+mod tests {
+    use unicode_segmentation::UnicodeSegmentation;
+    use unicode_width::UnicodeWidthStr;
+
+    use super::*;
+
+    /// Wraps the given content, checking that no line exceeds `max_width`.
+    fn wrap(content: &str, max_width: usize) -> Vec<String> {
+        let lines = Cell::new(content).wrapped_content(max_width);
+        for line in lines.iter() {
+            assert!(
+                line.width() <= max_width.max(1),
+                "{line:?} exceeds {max_width} columns"
+            );
+        }
+        lines
+    }
+
+    /// Checks that the given lines hold the grapheme clusters of `content`, in order and each kept whole.
+    fn assert_whole_clusters(lines: &[String], content: &str) {
+        let clusters: Vec<&str> = lines.iter().flat_map(|line| line.graphemes(true)).collect();
+        assert_eq!(clusters, content.graphemes(true).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn parses_numbers_with_units() {
+        let numeric_value = |content: &str| Cell::new(content).numeric_value();
+        assert_eq!(numeric_value(" 42 "), Some(42.0));
+        assert_eq!(numeric_value("-0.25"), Some(-0.25));
+        assert_eq!(numeric_value("+.5"), Some(0.5));
+        assert_eq!(numeric_value("12ms"), Some(12.0));
+        assert_eq!(numeric_value("5.5 GiB"), Some(5.5));
+        assert_eq!(numeric_value("0.2%"), Some(0.2));
+        assert_eq!(numeric_value("1,234,567.8"), Some(1234567.8));
+        assert_eq!(numeric_value("10_000"), Some(10000.0));
+        assert_eq!(numeric_value("\x1b[1m87\x1b[0mms"), Some(87.0));
+    }
+
+    #[test]
+    fn rejects_contents_which_are_not_numbers() {
+        let numeric_value = |content: &str| Cell::new(content).numeric_value();
+        for content in [
+            "",
+            "-",
+            ".",
+            "ms",
+            "2024-01-05",
+            "2024 latency",
+            "1,5",
+            "1,23,456",
+            ",123",
+            "1.2.3",
+            "12  ms",
+            "v1.2",
+        ] {
+            assert_eq!(numeric_value(content), None, "{content:?}");
+        }
+    }
+
+    #[test]
+    fn wraps_cjk_at_odd_widths() {
+        let content = "漢字のテキストを折り返す";
+        for max_width in [3, 5, 7, 9] {
+            let lines = wrap(content, max_width);
+            assert_whole_clusters(&lines, content);
+            assert!(lines[..lines.len() - 1]
+                .iter()
+                .all(|line| line.width() == max_width - 1));
+        }
+    }
+
+    #[test]
+    fn keeps_emoji_sequences_whole() {
+        let content = "👨\u{200d}👩\u{200d}👧\u{200d}👦👩🏽\u{200d}💻🇫🇷🇯🇵🏳\u{fe0f}\u{200d}🌈";
+        for max_width in 2..8 {
+            assert_whole_clusters(&wrap(content, max_width), content);
+        }
+        assert_eq!(wrap("🇫🇷🇯🇵", 3), ["🇫🇷", "🇯🇵"]);
+    }
+
+    #[test]
+    fn keeps_combining_marks_with_their_base() {
+        let content = "e\u{301}a\u{308}o\u{302}\u{323}u\u{30a}";
+        assert_eq!(
+            wrap(content, 2),
+            ["e\u{301}a\u{308}", "o\u{302}\u{323}u\u{30a}"]
+        );
+        for max_width in 1..5 {
+            assert_whole_clusters(&wrap(content, max_width), content);
+        }
+    }
+
+    #[test]
+    fn replaces_clusters_too_wide_to_fit_with_an_ellipsis() {
+        assert_eq!(wrap("漢a字", 1), ["…", "a", "…"]);
+        assert_eq!(wrap("👨\u{200d}👩\u{200d}👧", 1), ["…"]);
+    }
+
+    #[test]
+    fn keeps_content_without_width() {
+        assert_eq!(wrap("abc", 0), ["a", "b", "c"]);
+        assert_eq!(wrap("漢字", 0), ["…", "…"]);
+        assert_eq!(wrap("", 0), [""]);
+    }
+}
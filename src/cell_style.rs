@@ -0,0 +1,196 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+/// A terminal color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// The black color.
+    Black,
+    /// The red color.
+    Red,
+    /// The green color.
+    Green,
+    /// The yellow color.
+    Yellow,
+    /// The blue color.
+    Blue,
+    /// The magenta color.
+    Magenta,
+    /// The cyan color.
+    Cyan,
+    /// The white color.
+    White,
+    /// The bright black (gray) color.
+    BrightBlack,
+    /// The bright red color.
+    BrightRed,
+    /// The bright green color.
+    BrightGreen,
+    /// The bright yellow color.
+    BrightYellow,
+    /// The bright blue color.
+    BrightBlue,
+    /// The bright magenta color.
+    BrightMagenta,
+    /// The bright cyan color.
+    BrightCyan,
+    /// The bright white color.
+    BrightWhite,
+    /// A color from the 256-color palette.
+    Ansi(u8),
+    /// A 24-bit RGB color.
+    Rgb(u8, u8, u8),
+}
+
+impl Color {
+    /// Returns the SGR parameters for this color, as a foreground (`base = 30`) or background (`base = 40`) color.
+    fn sgr_parameters(&self, base: u8) -> String {
+        match *self {
+            Color::Black => base.to_string(),
+            Color::Red => (base + 1).to_string(),
+            Color::Green => (base + 2).to_string(),
+            Color::Yellow => (base + 3).to_string(),
+            Color::Blue => (base + 4).to_string(),
+            Color::Magenta => (base + 5).to_string(),
+            Color::Cyan => (base + 6).to_string(),
+            Color::White => (base + 7).to_string(),
+            Color::BrightBlack => (base + 60).to_string(),
+            Color::BrightRed => (base + 61).to_string(),
+            Color::BrightGreen => (base + 62).to_string(),
+            Color::BrightYellow => (base + 63).to_string(),
+            Color::BrightBlue => (base + 64).to_string(),
+            Color::BrightMagenta => (base + 65).to_string(),
+            Color::BrightCyan => (base + 66).to_string(),
+            Color::BrightWhite => (base + 67).to_string(),
+            Color::Ansi(index) => format!("{};5;{}", base + 8, index),
+            Color::Rgb(r, g, b) => format!("{};2;{};{};{}", base + 8, r, g, b),
+        }
+    }
+}
+
+/// The visual attributes applied to the contents of a cell when rendering.
+///
+/// Unlike ANSI sequences baked into the content of a cell, a cell style covers the whole cell (including its padding),
+/// and is reapplied after any reset sequence found within the content.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct CellStyle {
+    /// The foreground color.
+    pub foreground: Option<Color>,
+    /// The background color.
+    pub background: Option<Color>,
+    /// Whether the text should be bold.
+    pub is_bold: bool,
+    /// Whether the text should be dimmed.
+    pub is_dimmed: bool,
+    /// Whether the text should be italic.
+    pub is_italic: bool,
+    /// Whether the text should be underlined.
+    pub is_underlined: bool,
+}
+
+impl CellStyle {
+    /// Creates a new empty cell style, which does not alter the cell.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the foreground color.
+    pub fn with_foreground(mut self, color: Color) -> Self {
+        self.foreground = Some(color);
+        self
+    }
+
+    /// Sets the background color.
+    pub fn with_background(mut self, color: Color) -> Self {
+        self.background = Some(color);
+        self
+    }
+
+    /// Makes the text bold.
+    pub fn with_bold(mut self) -> Self {
+        self.is_bold = true;
+        self
+    }
+
+    /// Makes the text dimmed.
+    pub fn with_dimmed(mut self) -> Self {
+        self.is_dimmed = true;
+        self
+    }
+
+    /// Makes the text italic.
+    pub fn with_italic(mut self) -> Self {
+        self.is_italic = true;
+        self
+    }
+
+    /// Makes the text underlined.
+    pub fn with_underline(mut self) -> Self {
+        self.is_underlined = true;
+        self
+    }
+
+    /// Returns whether this style does not alter the cell at all.
+    pub fn is_plain(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Layers `other` on top of this style.
+    ///
+    /// Colors set in `other` replace the ones from this style, and attributes are combined.
+    pub fn merge(self, other: CellStyle) -> CellStyle {
+        CellStyle {
+            foreground: other.foreground.or(self.foreground),
+            background: other.background.or(self.background),
+            is_bold: self.is_bold || other.is_bold,
+            is_dimmed: self.is_dimmed || other.is_dimmed,
+            is_italic: self.is_italic || other.is_italic,
+            is_underlined: self.is_underlined || other.is_underlined,
+        }
+    }
+
+    /// Returns the SGR sequence that enables this style.
+    fn prefix(&self) -> String {
+        let mut params = Vec::new();
+        if self.is_bold {
+            params.push(String::from("1"));
+        }
+        if self.is_dimmed {
+            params.push(String::from("2"));
+        }
+        if self.is_italic {
+            params.push(String::from("3"));
+        }
+        if self.is_underlined {
+            params.push(String::from("4"));
+        }
+        if let Some(color) = self.foreground {
+            params.push(color.sgr_parameters(30));
+        }
+        if let Some(color) = self.background {
+            params.push(color.sgr_parameters(40));
+        }
+        format!("\x1b[{}m", params.join(";"))
+    }
+
+    /// Wraps the given string with the SGR sequences of this style.
+    pub(crate) fn paint(&self, s: &str) -> String {
+        if self.is_plain() {
+            return s.to_string();
+        }
+        let prefix = self.prefix();
+        let mut painted = prefix.clone();
+        let mut last_end = 0;
+        for m in RESET_REGEX.find_iter(s) {
+            painted.push_str(&s[last_end..m.end()]);
+            painted.push_str(&prefix);
+            last_end = m.end();
+        }
+        painted.push_str(&s[last_end..]);
+        painted.push_str("\x1b[0m");
+        painted
+    }
+}
+
+static RESET_REGEX: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\x1b\[0*m").unwrap());
@@ -0,0 +1,80 @@
+use crate::cell::Cell;
+use crate::row::Row;
+
+/// A reference to a column of a table, either by index or by header name.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ColumnSelector {
+    /// The column at the given index.
+    Index(usize),
+    /// The column whose header cell has the given content (ignoring ANSI sequences and surrounding whitespace).
+    Name(String),
+}
+
+impl From<usize> for ColumnSelector {
+    fn from(index: usize) -> Self {
+        ColumnSelector::Index(index)
+    }
+}
+
+impl From<&str> for ColumnSelector {
+    fn from(name: &str) -> Self {
+        ColumnSelector::Name(name.to_string())
+    }
+}
+
+impl From<String> for ColumnSelector {
+    fn from(name: String) -> Self {
+        ColumnSelector::Name(name)
+    }
+}
+
+/// Rebuilds a row from the given source columns, in order.
+///
+/// Consecutive (distinct) columns occupied by the same source cell are merged back into a single spanning cell,
+/// so spans shrink as the columns they cover are removed.
+/// Repeated columns are duplicated, and columns the row does not reach are skipped.
+pub(crate) fn project_row(row: &Row, columns: &[usize]) -> Row {
+    let mut cells: Vec<Cell> = Vec::new();
+    let mut last_source = None;
+    let mut last_column = None;
+    for &column in columns {
+        let source = row
+            .positioned_cells()
+            .position(|(position, cell)| (position..position + cell.column_span).contains(&column));
+        match source {
+            Some(source) if last_source == Some(source) && last_column != Some(column) => {
+                if let Some(cell) = cells.last_mut() {
+                    cell.column_span += 1;
+                }
+            }
+            Some(source) => cells.push(row.cells[source].clone().with_column_span(1)),
+            None => {}
+        }
+        last_source = source;
+        last_column = Some(column);
+    }
+    Row {
+        cells,
+        ..row.clone()
+    }
+}
+
+/// Inserts a cell into a row, so that it occupies the column at the given index.
+///
+/// If the index falls within a spanning cell, that cell grows to cover the new column instead, and the given cell is discarded.
+/// If the row does not reach the index, the cell is appended to the row.
+pub(crate) fn insert_cell(row: &mut Row, index: usize, cell: Cell) {
+    let mut position = 0;
+    for (cell_index, existing) in row.cells.iter_mut().enumerate() {
+        if position == index {
+            row.cells.insert(cell_index, cell);
+            return;
+        }
+        if index < position + existing.column_span {
+            existing.column_span += 1;
+            return;
+        }
+        position += existing.column_span;
+    }
+    row.cells.push(cell);
+}
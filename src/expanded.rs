@@ -0,0 +1,115 @@
+use std::borrow::Cow;
+
+use crate::bidi;
+use crate::cell::Cell;
+use crate::row::RowKind;
+use crate::Table;
+
+/// When to render a table as a list of records, instead of a grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Expanded {
+    /// Always render the table as a grid.
+    Off,
+    /// Always render the table as a list of records.
+    On,
+    /// Render the table as a list of records only when the grid would be wider than the maximum width of the table
+    /// (or the width of the terminal, if unset) without hiding or shrinking columns.
+    Auto,
+}
+
+impl Table {
+    /// Renders the table as a list of records, one per body row.
+    ///
+    /// Each record is a two-column block, labelling each value with the name of its column (from the last header row),
+    /// and records are separated by `─[ RECORD n ]─` rules.
+    /// Values are wrapped to fit within the maximum width of the table, if any.
+    /// The direction of the table does not affect records, which always start with their labels.
+    /// Without any record, only the placeholder of the table (if any) is rendered.
+    pub fn render_expanded(&self) -> String {
+        let rows = self.prepare_rows();
+        let column_count = self.column_count();
+
+        let header = rows.iter().rev().find(|row| row.kind == RowKind::Header);
+        let labels: Vec<String> = (0..column_count)
+            .map(|column_index| {
+                header
+                    .and_then(|row| row.cell_at_column(column_index))
+                    .filter(|cell| cell.column_span == 1)
+                    .map(|cell| cell.content.clone())
+                    .unwrap_or_else(|| format!("column {}", column_index + 1))
+            })
+            .map(|label| match self.has_bidi_reordering {
+                true => bidi::reorder_line(&label).into_owned(),
+                false => label,
+            })
+            .collect();
+        let label_width = labels
+            .iter()
+            .map(|label| crate::visible_width(label))
+            .max()
+            .unwrap_or(0);
+
+        let records: Vec<Vec<(&str, &Cell)>> = rows
+            .iter()
+            .filter(|row| row.kind == RowKind::Body)
+            .map(|row| {
+                row.positioned_cells()
+                    .map(|(column_index, cell)| (labels[column_index].as_str(), cell))
+                    .collect()
+            })
+            .collect();
+        if records.is_empty() {
+            return self.placeholder.clone().unwrap_or_default();
+        }
+
+        let value_width = records
+            .iter()
+            .flatten()
+            .map(|(_, cell)| crate::visible_width(&cell.content))
+            .max()
+            .unwrap_or(0);
+        let value_width = match self.max_width {
+            Some(max_width) => value_width.min(max_width.saturating_sub(label_width + 3).max(1)),
+            None => value_width,
+        };
+        let total_width = label_width + 3 + value_width;
+
+        let mut rendered = String::new();
+        for (index, record) in records.iter().enumerate() {
+            if index > 0 {
+                rendered.push('\n');
+            }
+            let title = format!("{}[ RECORD {} ]", self.style.horizontal, index + 1);
+            let title_width = crate::visible_width(&title);
+            rendered.push_str(&title);
+            rendered.extend(std::iter::repeat_n(
+                self.style.horizontal,
+                total_width.saturating_sub(title_width),
+            ));
+
+            for (label, cell) in record.iter() {
+                let lines = cell.wrapped_content(value_width);
+                for (line_index, line) in lines.iter().enumerate() {
+                    rendered.push('\n');
+                    let label = if line_index == 0 { *label } else { "" };
+                    rendered.push_str(label);
+                    rendered.extend(std::iter::repeat_n(
+                        ' ',
+                        label_width - crate::visible_width(label),
+                    ));
+                    rendered.push(' ');
+                    rendered.push(self.style.vertical);
+                    rendered.push(' ');
+                    let line = if self.has_bidi_reordering {
+                        bidi::reorder_line(line)
+                    } else {
+                        Cow::Borrowed(line.as_str())
+                    };
+                    rendered.push_str(&cell.linked_line(&line));
+                }
+            }
+        }
+
+        rendered
+    }
+}
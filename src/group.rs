@@ -0,0 +1,58 @@
+use std::collections::HashMap;
+
+use crate::aggregation::{self, Aggregation};
+use crate::row::{Row, RowKind};
+
+/// Makes the last cell of a group header row span the remaining columns of a table with the given number of columns.
+pub(crate) fn fill_group_header(row: &mut Row, column_count: usize) {
+    if row.kind != RowKind::GroupHeader {
+        return;
+    }
+    let spanned_columns: usize = row.cells.iter().map(|cell| cell.column_span).sum();
+    if let Some(cell) = row.cells.last_mut() {
+        cell.column_span += column_count.saturating_sub(spanned_columns);
+    }
+}
+
+/// Inserts a subtotal row after each group of the given rows, for a table with the given number of columns.
+///
+/// Groups are the runs of body rows following a group header row.
+/// Each column is aggregated with its aggregation in `aggregations`, or summed by default.
+pub(crate) fn insert_subtotals(
+    rows: Vec<Row>,
+    column_count: usize,
+    aggregations: &HashMap<usize, Aggregation>,
+) -> Vec<Row> {
+    let mut result = Vec::with_capacity(rows.len());
+    let mut group_start = None;
+    for row in rows {
+        if row.kind != RowKind::Body {
+            close_group(&mut result, group_start.take(), column_count, aggregations);
+        }
+        let is_group_header = row.kind == RowKind::GroupHeader;
+        result.push(row);
+        if is_group_header {
+            group_start = Some(result.len());
+        }
+    }
+    close_group(&mut result, group_start, column_count, aggregations);
+    result
+}
+
+/// Adds the subtotal row of the group starting at `group_start` (if any, and if not empty) at the end of the rows.
+fn close_group(
+    rows: &mut Vec<Row>,
+    group_start: Option<usize>,
+    column_count: usize,
+    aggregations: &HashMap<usize, Aggregation>,
+) {
+    if let Some(group_start) = group_start.filter(|start| *start < rows.len()) {
+        let subtotal = aggregation::aggregate_rows(
+            &rows[group_start..],
+            column_count,
+            |column_index| aggregations.get(&column_index).or(Some(&Aggregation::Sum)),
+            "Subtotal",
+        );
+        rows.push(subtotal.without_top_border());
+    }
+}
@@ -2,19 +2,50 @@
 
 //! Tableau is a library for creating tables in Rust.
 
+use std::borrow::Cow;
 use std::collections::HashMap;
-use std::sync::LazyLock;
+use std::fmt;
+use std::sync::{Arc, LazyLock};
 
+mod aggregation;
+mod bidi;
 mod cell;
+mod cell_style;
+mod column;
+mod expanded;
+mod group;
+mod live;
+mod numbering;
+mod pager;
+mod pagination;
 mod row;
+mod rule;
+mod slice;
+mod sort;
+mod streaming;
 mod style;
+mod terminal;
+mod value;
 
 use regex::Regex;
 use unicode_width::UnicodeWidthStr;
 
+pub use crate::aggregation::{Aggregation, CustomAggregation};
+pub use crate::bidi::Direction;
 pub use crate::cell::{Alignment, Cell};
-pub use crate::row::Row;
+pub use crate::cell_style::{CellStyle, Color};
+pub use crate::column::ColumnSelector;
+pub use crate::expanded::Expanded;
+pub use crate::live::LiveTable;
+pub use crate::numbering::RowNumbering;
+pub use crate::row::{Row, RowKind};
+pub use crate::rule::{CellPredicate, Rule};
+pub use crate::sort::Order;
+pub use crate::streaming::{ColumnWidths, StreamingTable};
 pub use crate::style::Style;
+pub use crate::value::{Value, ValueFormatter};
+
+use crate::rule::ColumnStats;
 
 /// The main struct for creating a table.
 #[derive(Debug, Clone, PartialEq)]
@@ -31,6 +62,22 @@ pub struct Table {
     ///
     /// It overrides `max_column_width`.
     pub max_column_widths: HashMap<usize, usize>,
+    /// The minimum width of all columns.
+    ///
+    /// It is overridden by the values in `min_column_widths`.
+    pub min_column_width: Option<usize>,
+    /// The minimum widths of specific columns.
+    ///
+    /// It overrides `min_column_width`, and takes precedence over the maximum widths.
+    pub min_column_widths: HashMap<usize, usize>,
+    /// The fixed width of all columns.
+    ///
+    /// It is overridden by the values in `fixed_column_widths`.
+    pub fixed_column_width: Option<usize>,
+    /// The fixed widths of specific columns, regardless of their contents.
+    ///
+    /// It overrides `fixed_column_width`, and takes precedence over the minimum and maximum widths.
+    pub fixed_column_widths: HashMap<usize, usize>,
     /// Whether or not to vertically separate rows in the table.
     pub has_separate_rows: bool,
     /// Whether the table should have a top border.
@@ -39,6 +86,108 @@ pub struct Table {
     pub has_top_border: bool,
     /// Whether the table should have a bottom border.
     pub has_bottom_border: bool,
+    /// The maximum width of the whole table, if any.
+    pub max_width: Option<usize>,
+    /// The distance between tab stops, used to expand tabs in cell contents.
+    pub tab_width: usize,
+    /// The character replacing control characters (other than newlines and tabs) in cell contents.
+    ///
+    /// If `None`, control characters are removed.
+    pub control_character_replacement: Option<char>,
+    /// The direction of the table.
+    ///
+    /// Right-to-left tables have their columns (and the left and right characters of their style) mirrored,
+    /// the first column being on the right.
+    pub direction: Direction,
+    /// Whether to reorder the lines of cells for display, following the Unicode Bidirectional Algorithm.
+    ///
+    /// This makes right-to-left text (like Arabic or Hebrew) readable in terminals which do not reorder it themselves.
+    /// Lines containing ANSI sequences are never reordered.
+    pub has_bidi_reordering: bool,
+    /// When to render the table as a list of records, instead of a grid.
+    pub expanded: Expanded,
+    /// The priorities of specific columns, used to hide columns when the table is wider than `max_width`.
+    ///
+    /// Columns with the lowest priorities are hidden first, and columns without a priority are never hidden.
+    /// At least one column is always left visible.
+    pub column_priorities: HashMap<usize, u32>,
+    /// Whether to mention the number of hidden columns below the table.
+    pub has_hidden_columns_marker: bool,
+    /// Whether to add a `Page 2/7` line below each page, when paginating the table.
+    pub has_page_numbers: bool,
+    /// The grow weights of specific columns, used to distribute extra width when the table is narrower than `max_width`.
+    ///
+    /// Columns without a grow weight never grow, and the table is only widened if at least one column has a grow weight.
+    pub column_grow_weights: HashMap<usize, u32>,
+    /// The shrink weights of specific columns, used to distribute the width to remove when the table is wider than `max_width`.
+    ///
+    /// If no column has a shrink weight, the widest columns are shrunk first.
+    /// Otherwise, columns shrink proportionally to their shrink weight multiplied by their width,
+    /// columns without a shrink weight having a weight of 1.
+    pub column_shrink_weights: HashMap<usize, u32>,
+    /// The background colors alternately applied to body rows, if any.
+    ///
+    /// Header, footer and group header rows are never striped, and do not break the alternation.
+    pub zebra_stripes: Option<(Color, Color)>,
+    /// The function computing the style of each row, if any.
+    ///
+    /// It is applied on top of `zebra_stripes`.
+    pub row_styler: Option<RowStyler>,
+    /// The conditional formatting rules of specific columns.
+    ///
+    /// They are evaluated in order when rendering, and applied on top of the row styles.
+    /// They only apply to body rows, and rules comparing cells to the rest of their column only consider body rows.
+    pub column_rules: HashMap<usize, Vec<Rule>>,
+    /// The style of group header rows, applied on top of the row styles.
+    pub group_header_style: CellStyle,
+    /// Whether to add a subtotal row after each group, aggregating its columns.
+    ///
+    /// Columns are aggregated with their aggregation in `column_aggregations`, or summed by default.
+    pub has_group_subtotals: bool,
+    /// The text displayed in place of the body rows of the table, when it has none.
+    ///
+    /// Without a placeholder, a table without any row renders as an empty string.
+    pub placeholder: Option<String>,
+    /// The settings of the column numbering the body rows of the table, if any.
+    ///
+    /// The column is added before the first one when rendering, and is never hidden.
+    pub row_numbering: Option<RowNumbering>,
+    /// The aggregations of specific columns, summarizing their body rows in a footer row added at the end of the table.
+    pub column_aggregations: HashMap<usize, Aggregation>,
+    /// The formatters for the typed values of specific columns.
+    ///
+    /// Columns without a formatter use the default one.
+    pub column_formatters: HashMap<usize, ValueFormatter>,
+}
+
+type RowStylerFn = dyn Fn(usize, &Row) -> CellStyle + Send + Sync;
+
+/// A function computing the style of a row from its index and its contents.
+#[derive(Clone)]
+pub struct RowStyler(Arc<RowStylerFn>);
+
+impl RowStyler {
+    /// Creates a new row styler from the given function.
+    pub fn new(styler: impl Fn(usize, &Row) -> CellStyle + Send + Sync + 'static) -> Self {
+        Self(Arc::new(styler))
+    }
+
+    /// Computes the style of the given row.
+    pub fn style(&self, index: usize, row: &Row) -> CellStyle {
+        (self.0)(index, row)
+    }
+}
+
+impl fmt::Debug for RowStyler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("RowStyler(..)")
+    }
+}
+
+impl PartialEq for RowStyler {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
 }
 
 impl Default for Table {
@@ -55,9 +204,33 @@ impl Table {
             style: Style::rounded(),
             max_column_width: None,
             max_column_widths: HashMap::default(),
+            min_column_width: None,
+            min_column_widths: HashMap::default(),
+            fixed_column_width: None,
+            fixed_column_widths: HashMap::default(),
             has_separate_rows: true,
             has_top_border: true,
             has_bottom_border: true,
+            max_width: None,
+            tab_width: 8,
+            control_character_replacement: Some('\u{FFFD}'),
+            direction: Direction::LeftToRight,
+            has_bidi_reordering: false,
+            expanded: Expanded::Off,
+            column_priorities: HashMap::default(),
+            has_hidden_columns_marker: false,
+            has_page_numbers: false,
+            column_grow_weights: HashMap::default(),
+            column_shrink_weights: HashMap::default(),
+            zebra_stripes: None,
+            row_styler: None,
+            column_rules: HashMap::default(),
+            group_header_style: CellStyle::new().with_bold(),
+            has_group_subtotals: false,
+            placeholder: None,
+            row_numbering: None,
+            column_aggregations: HashMap::default(),
+            column_formatters: HashMap::default(),
         }
     }
 
@@ -73,6 +246,17 @@ impl Table {
         self
     }
 
+    /// Adds a group of rows to the table, preceded by a group header row with the given title.
+    pub fn with_group(mut self, title: impl ToString, rows: impl IntoIterator<Item = Row>) -> Self {
+        self.rows.push(
+            Row::new()
+                .with_kind(RowKind::GroupHeader)
+                .with_cell(Cell::new(title)),
+        );
+        self.rows.extend(rows);
+        self
+    }
+
     /// Sets the style of the table.
     pub fn with_style(mut self, style: Style) -> Self {
         self.style = style;
@@ -91,6 +275,40 @@ impl Table {
         self
     }
 
+    /// Sets the minimum width for all columns.
+    ///
+    /// Columns are never narrower than a single character, with padding.
+    pub fn with_min_column_width(mut self, min_column_width: usize) -> Self {
+        self.min_column_width = Some(min_column_width);
+        self
+    }
+
+    /// Sets the minimum width for a specific column.
+    pub fn with_min_column_width_at_index(mut self, index: usize, min_column_width: usize) -> Self {
+        self.min_column_widths.insert(index, min_column_width);
+        self
+    }
+
+    /// Sets a fixed width for all columns.
+    ///
+    /// Columns are never narrower than a single character, with padding.
+    pub fn with_fixed_column_width(mut self, fixed_column_width: usize) -> Self {
+        self.fixed_column_width = Some(fixed_column_width);
+        self
+    }
+
+    /// Sets a fixed width for a specific column.
+    ///
+    /// This is useful for multiple tables printed one after another to line up, regardless of their contents.
+    pub fn with_fixed_column_width_at_index(
+        mut self,
+        index: usize,
+        fixed_column_width: usize,
+    ) -> Self {
+        self.fixed_column_widths.insert(index, fixed_column_width);
+        self
+    }
+
     /// Disables the vertical separation of rows.
     pub fn without_separate_rows(mut self) -> Self {
         self.has_separate_rows = false;
@@ -109,55 +327,836 @@ impl Table {
         self
     }
 
-    /// Calculates the widths of the columns in the table.
-    fn calculate_column_widths(&self) -> Vec<usize> {
-        let max_number_of_columns = self
+    /// Sets the maximum width of the whole table.
+    pub fn with_max_width(mut self, max_width: usize) -> Self {
+        self.max_width = Some(max_width);
+        self
+    }
+
+    /// Sets the distance between tab stops, used to expand tabs in cell contents.
+    pub fn with_tab_width(mut self, tab_width: usize) -> Self {
+        self.tab_width = tab_width;
+        self
+    }
+
+    /// Sets the character replacing control characters (other than newlines and tabs) in cell contents.
+    pub fn with_control_character_replacement(mut self, replacement: char) -> Self {
+        self.control_character_replacement = Some(replacement);
+        self
+    }
+
+    /// Removes control characters (other than newlines and tabs) from cell contents, instead of replacing them.
+    pub fn without_control_characters(mut self) -> Self {
+        self.control_character_replacement = None;
+        self
+    }
+
+    /// Sets the maximum width of the whole table to the width of the terminal, if any.
+    pub fn with_terminal_width(mut self) -> Self {
+        self.max_width = terminal::terminal_width();
+        self
+    }
+
+    /// Sets the direction of the table.
+    ///
+    /// Right-to-left tables are rendered with their first column on the right.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Enables reordering the lines of cells for display, following the Unicode Bidirectional Algorithm.
+    pub fn with_bidi_reordering(mut self) -> Self {
+        self.has_bidi_reordering = true;
+        self
+    }
+
+    /// Sets when to render the table as a list of records, instead of a grid.
+    pub fn with_expanded(mut self, expanded: Expanded) -> Self {
+        self.expanded = expanded;
+        self
+    }
+
+    /// Sets the priority of a specific column.
+    ///
+    /// When the table is wider than its maximum width, columns with the lowest priorities are hidden first, before wrapping the remaining ones.
+    pub fn with_column_priority(mut self, index: usize, priority: u32) -> Self {
+        self.column_priorities.insert(index, priority);
+        self
+    }
+
+    /// Sets the grow weight of a specific column (like `flex-grow` in CSS).
+    ///
+    /// When the table is narrower than its maximum width, the extra width is distributed to columns proportionally to their grow weights,
+    /// so that the table fills its maximum width exactly.
+    pub fn with_column_grow_weight(mut self, index: usize, weight: u32) -> Self {
+        self.column_grow_weights.insert(index, weight);
+        self
+    }
+
+    /// Sets the shrink weight of a specific column (like `flex-shrink` in CSS).
+    ///
+    /// When the table is wider than its maximum width, columns shrink proportionally to their shrink weights multiplied by their widths.
+    /// A weight of 0 prevents the column from shrinking.
+    pub fn with_column_shrink_weight(mut self, index: usize, weight: u32) -> Self {
+        self.column_shrink_weights.insert(index, weight);
+        self
+    }
+
+    /// Mentions the number of hidden columns below the table (like `+3 hidden columns`).
+    pub fn with_hidden_columns_marker(mut self) -> Self {
+        self.has_hidden_columns_marker = true;
+        self
+    }
+
+    /// Enables adding a `Page 2/7` line below each page, when paginating the table.
+    pub fn with_page_numbers(mut self) -> Self {
+        self.has_page_numbers = true;
+        self
+    }
+
+    /// Alternates the background color of rows between `first` and `second`.
+    ///
+    /// Stripes only alternate over body rows, header and footer rows keeping their own style.
+    /// The background covers the whole row, including cell padding and spanning cells.
+    pub fn with_zebra_stripes(mut self, first: Color, second: Color) -> Self {
+        self.zebra_stripes = Some((first, second));
+        self
+    }
+
+    /// Sets the function computing the style of each row, from its index and its contents.
+    ///
+    /// The function sees the rows as they were added to the table (with their values formatted), without the row numbering column,
+    /// and with their cells in logical order in right-to-left tables.
+    pub fn with_row_styler(
+        mut self,
+        styler: impl Fn(usize, &Row) -> CellStyle + Send + Sync + 'static,
+    ) -> Self {
+        self.row_styler = Some(RowStyler::new(styler));
+        self
+    }
+
+    /// Adds a conditional formatting rule to a specific column.
+    ///
+    /// Rules only apply to (and only compare cells of) body rows.
+    pub fn with_column_rule(mut self, index: usize, rule: Rule) -> Self {
+        self.column_rules.entry(index).or_default().push(rule);
+        self
+    }
+
+    /// Sets the style of group header rows.
+    pub fn with_group_header_style(mut self, style: CellStyle) -> Self {
+        self.group_header_style = style;
+        self
+    }
+
+    /// Enables adding a subtotal row after each group, aggregating its columns.
+    ///
+    /// Columns without an aggregation are summed, if their typed values can be added together (like numbers, durations or sizes).
+    pub fn with_group_subtotals(mut self) -> Self {
+        self.has_group_subtotals = true;
+        self
+    }
+
+    /// Sets the text displayed in place of the body rows of the table, when it has none (like `No results`).
+    ///
+    /// It is rendered as a centered footer row spanning all columns, below the header rows.
+    pub fn with_placeholder(mut self, placeholder: impl ToString) -> Self {
+        self.placeholder = Some(placeholder.to_string());
+        self
+    }
+
+    /// Enables numbering the body rows of the table, in a column added before the first one when rendering.
+    ///
+    /// Header, footer and group header rows are not numbered.
+    pub fn with_row_numbers(mut self, numbering: RowNumbering) -> Self {
+        self.row_numbering = Some(numbering);
+        self
+    }
+
+    /// Sets the aggregation of a specific column, summarizing its body rows in a footer row added at the end of the table.
+    ///
+    /// Aggregated values are formatted with the formatter of the column.
+    /// Tables without body rows only have the footer row along with their placeholder, if any.
+    pub fn with_column_aggregation(mut self, index: usize, aggregation: Aggregation) -> Self {
+        self.column_aggregations.insert(index, aggregation);
+        self
+    }
+
+    /// Sets the formatter for the typed values of a specific column.
+    pub fn with_column_formatter(mut self, index: usize, formatter: ValueFormatter) -> Self {
+        self.column_formatters.insert(index, formatter);
+        self
+    }
+
+    /// Sorts the body rows of the table by the values of a column.
+    ///
+    /// See [`Table::sort_by_columns`] for how the rows are sorted.
+    pub fn sort_by_column(&mut self, index: usize, order: Order) {
+        self.sort_by_columns(&[(index, order)]);
+    }
+
+    /// Sorts the body rows of the table by the values of multiple columns, the first key being the most significant.
+    ///
+    /// Cells holding typed values are compared by value, and the others are compared naturally by content ("file2" comes before "file10").
+    /// Typed values of different kinds are grouped by kind: numbers, durations, timestamps, sizes, then booleans.
+    /// Missing cells and null values are always sorted last.
+    ///
+    /// Header rows, footer rows and rows containing spanning cells stay in place, and only the runs of rows between them are sorted.
+    /// The top borders of the rows also stay in place, so that the sorted rows keep the same separators.
+    pub fn sort_by_columns(&mut self, keys: &[(usize, Order)]) {
+        let is_sortable = |row: &Row| {
+            row.kind == RowKind::Body && row.cells.iter().all(|cell| cell.column_span == 1)
+        };
+
+        let mut start = 0;
+        while start < self.rows.len() {
+            if !is_sortable(&self.rows[start]) {
+                start += 1;
+                continue;
+            }
+            let length = self.rows[start..]
+                .iter()
+                .take_while(|row| is_sortable(row))
+                .count();
+            let run = &mut self.rows[start..start + length];
+            let top_borders: Vec<bool> = run.iter().map(|row| row.has_top_border).collect();
+            run.sort_by(|a, b| {
+                keys.iter()
+                    .fold(std::cmp::Ordering::Equal, |ordering, &(index, order)| {
+                        ordering.then_with(|| {
+                            sort::compare_cells(
+                                a.cell_at_column(index),
+                                b.cell_at_column(index),
+                                order,
+                            )
+                        })
+                    })
+            });
+            for (row, has_top_border) in run.iter_mut().zip(top_borders) {
+                row.has_top_border = has_top_border;
+            }
+            start += length;
+        }
+    }
+
+    /// Returns the number of columns in the table.
+    pub fn column_count(&self) -> usize {
+        count_columns(&self.rows)
+    }
+
+    /// Returns the index of the column referred to by the given selector, if it exists.
+    ///
+    /// Names are looked up among the cells of header rows which do not span multiple columns.
+    pub fn column_index(&self, column: impl Into<ColumnSelector>) -> Option<usize> {
+        match column.into() {
+            ColumnSelector::Index(index) => (index < self.column_count()).then_some(index),
+            ColumnSelector::Name(name) => self
+                .rows
+                .iter()
+                .filter(|row| row.kind == RowKind::Header)
+                .flat_map(|row| row.positioned_cells())
+                .find(|(_, cell)| {
+                    cell.column_span == 1
+                        && ANSI_REGEX.replace_all(&cell.content, "").trim() == name.trim()
+                })
+                .map(|(position, _)| position),
+        }
+    }
+
+    /// Groups the body rows of the table by the contents of a column, each group being preceded by a group header row titled after them.
+    ///
+    /// Groups are ordered by first appearance, and keep the order of their rows.
+    /// Header rows are placed before the groups, footer rows after them, and existing group header rows are removed.
+    pub fn group_by_column(&mut self, column: impl Into<ColumnSelector>) {
+        let Some(index) = self.column_index(column) else {
+            return;
+        };
+        let default_formatter = ValueFormatter::default();
+        let formatter = self
+            .column_formatters
+            .get(&index)
+            .unwrap_or(&default_formatter);
+
+        let mut headers = Vec::new();
+        let mut groups: Vec<(String, Vec<Row>)> = Vec::new();
+        let mut footers = Vec::new();
+        for row in std::mem::take(&mut self.rows) {
+            match row.kind {
+                RowKind::Header => headers.push(row),
+                RowKind::Footer => footers.push(row),
+                RowKind::GroupHeader => {}
+                RowKind::Body => {
+                    let title = match row.cell_at_column(index) {
+                        Some(Cell {
+                            value: Some(value), ..
+                        }) => formatter.format(value),
+                        Some(cell) => cell.content.clone(),
+                        None => String::new(),
+                    };
+                    match groups.iter_mut().find(|(it, _)| *it == title) {
+                        Some((_, rows)) => rows.push(row),
+                        None => groups.push((title, vec![row])),
+                    }
+                }
+            }
+        }
+
+        self.rows = headers;
+        for (title, rows) in groups {
+            self.rows.push(
+                Row::new()
+                    .with_kind(RowKind::GroupHeader)
+                    .with_cell(Cell::new(title)),
+            );
+            self.rows.extend(rows);
+        }
+        self.rows.extend(footers);
+    }
+
+    /// Keeps only the body rows matching the predicate.
+    ///
+    /// Header and footer rows are always kept.
+    pub fn filter_rows(&mut self, mut predicate: impl FnMut(&Row) -> bool) {
+        self.rows
+            .retain(|row| row.kind != RowKind::Body || predicate(row));
+    }
+
+    /// Keeps only the given columns, in the given order.
+    ///
+    /// Columns that do not exist are ignored, and columns selected multiple times are duplicated.
+    /// Spanning cells shrink to the selected columns they cover, as long as these remain adjacent.
+    /// Per-column settings (like maximum widths, rules and formatters) follow their columns.
+    pub fn select_columns<C: Into<ColumnSelector>>(
+        &mut self,
+        columns: impl IntoIterator<Item = C>,
+    ) {
+        let columns: Vec<usize> = columns
+            .into_iter()
+            .filter_map(|column| self.column_index(column))
+            .collect();
+        for row in self.rows.iter_mut() {
+            *row = column::project_row(row, &columns);
+        }
+        let sources: Vec<Option<usize>> = columns.into_iter().map(Some).collect();
+        self.remap_column_settings(&sources);
+    }
+
+    /// Removes a column from the table.
+    ///
+    /// Spanning cells covering the column shrink by one column, and are removed if they only covered that column.
+    pub fn drop_column(&mut self, column: impl Into<ColumnSelector>) {
+        let Some(index) = self.column_index(column) else {
+            return;
+        };
+        let columns: Vec<usize> = (0..self.column_count()).filter(|it| *it != index).collect();
+        self.select_columns(columns);
+    }
+
+    /// Inserts a column at the given index, using the given cells for each row in order.
+    ///
+    /// Rows without a matching cell receive an empty cell.
+    /// If the index falls within a spanning cell, that cell grows to cover the new column, and the row's cell is discarded.
+    /// Per-column settings (like maximum widths, rules and formatters) are shifted along with their columns.
+    pub fn insert_column(&mut self, index: usize, cells: impl IntoIterator<Item = Cell>) {
+        let column_count = self.column_count();
+        let mut cells = cells.into_iter();
+        for row in self.rows.iter_mut() {
+            let cell = cells.next().unwrap_or_else(|| Cell::new(""));
+            column::insert_cell(row, index, cell);
+        }
+        let sources: Vec<Option<usize>> = (0..index)
+            .map(Some)
+            .chain(std::iter::once(None))
+            .chain((index..column_count).map(Some))
+            .collect();
+        self.remap_column_settings(&sources);
+    }
+
+    /// Returns a new table with the rows and columns of this one swapped.
+    ///
+    /// Header rows become the first columns, so that each resulting row starts with the name of its field.
+    /// Since cells cannot span multiple rows, spanning cells are expanded into one cell followed by empty ones.
+    /// All the resulting rows are body rows, and the per-column settings of the table are discarded.
+    /// Typed values of columns with a formatter are formatted beforehand, their cells holding the formatted contents instead.
+    pub fn transpose(&self) -> Table {
+        let column_count = self.column_count();
+        let (headers, others): (Vec<&Row>, Vec<&Row>) = self
             .rows
             .iter()
-            .map(|row| row.cells.iter().map(|cell| cell.column_span).sum::<usize>())
-            .max()
-            .unwrap_or(0);
-
-        let mut column_widths = vec![0; max_number_of_columns];
-
-        for row in self.rows.iter() {
-            let mut spanned_columns = 0;
-            for cell in row.cells.iter() {
-                let cell_whole_width = cell.width();
-                let subcell_width = (cell_whole_width - cell.column_span + 1) / cell.column_span;
-                let leftover_width = (cell_whole_width - cell.column_span + 1) % cell.column_span;
-                for (index, column_width) in column_widths
-                    .iter_mut()
-                    .skip(spanned_columns)
-                    .take(cell.column_span)
-                    .enumerate()
+            .partition(|row| row.kind == RowKind::Header);
+
+        let grid: Vec<Vec<Cell>> = headers
+            .into_iter()
+            .chain(others)
+            .map(|row| {
+                let mut cells: Vec<Cell> = row
+                    .positioned_cells()
+                    .flat_map(|(column_index, cell)| {
+                        let formatter = self.column_formatters.get(&column_index);
+                        let cell = match (cell.value.as_ref(), formatter) {
+                            (Some(value), Some(formatter)) => Cell {
+                                content: formatter.format(value),
+                                value: None,
+                                ..cell.clone()
+                            },
+                            _ => cell.clone(),
+                        };
+                        let empty = Cell {
+                            content: String::new(),
+                            value: None,
+                            ..cell.clone()
+                        };
+                        let column_span = cell.column_span;
+                        std::iter::once(cell)
+                            .chain(std::iter::repeat_n(empty, column_span - 1))
+                            .map(|cell| cell.with_column_span(1))
+                    })
+                    .collect();
+                cells.resize(column_count, Cell::new(""));
+                cells
+            })
+            .collect();
+
+        let rows = (0..column_count).map(|column_index| {
+            Row::new().with_cells(grid.iter().map(|cells| cells[column_index].clone()))
+        });
+
+        Table {
+            rows: rows.collect(),
+            max_column_widths: HashMap::default(),
+            min_column_widths: HashMap::default(),
+            fixed_column_widths: HashMap::default(),
+            column_rules: HashMap::default(),
+            column_formatters: HashMap::default(),
+            column_aggregations: HashMap::default(),
+            column_priorities: HashMap::default(),
+            column_grow_weights: HashMap::default(),
+            column_shrink_weights: HashMap::default(),
+            ..self.clone()
+        }
+    }
+
+    /// Turns this right-to-left table, with the given number of columns, into a left-to-right table rendering like it.
+    ///
+    /// The rows (see [`mirror_row`]), the per-column settings and the style of the table are mirrored.
+    pub(crate) fn mirror(&mut self, column_count: usize) {
+        for row in self.rows.iter_mut() {
+            mirror_row(row, column_count);
+        }
+        let sources: Vec<Option<usize>> = (0..column_count).rev().map(Some).collect();
+        self.remap_column_settings(&sources);
+        self.style = self.style.mirrored();
+        self.direction = Direction::LeftToRight;
+    }
+
+    /// Makes the row styler of the table see the rows as currently prepared for rendering, instead of the rendered ones,
+    /// before changing the columns of the table (like numbering or mirroring them).
+    ///
+    /// Changing the columns keeps the rows in place, so each rendered row is styled from the original row at the same index.
+    pub(crate) fn style_original_rows(&mut self) {
+        let Some(styler) = self.row_styler.take() else {
+            return;
+        };
+        let rows = self.prepare_rows();
+        self.row_styler = Some(RowStyler::new(move |index, row| {
+            styler.style(index, rows.get(index).unwrap_or(row))
+        }));
+    }
+
+    /// Moves the per-column settings of the table, so that the column at each index takes the settings of its source column.
+    ///
+    /// Columns without a source are left without specific settings.
+    fn remap_column_settings(&mut self, sources: &[Option<usize>]) {
+        fn remap<T: Clone>(
+            map: &HashMap<usize, T>,
+            sources: &[Option<usize>],
+        ) -> HashMap<usize, T> {
+            sources
+                .iter()
+                .enumerate()
+                .filter_map(|(index, source)| Some((index, map.get(&(*source)?)?.clone())))
+                .collect()
+        }
+
+        self.max_column_widths = remap(&self.max_column_widths, sources);
+        self.min_column_widths = remap(&self.min_column_widths, sources);
+        self.fixed_column_widths = remap(&self.fixed_column_widths, sources);
+        self.column_rules = remap(&self.column_rules, sources);
+        self.column_formatters = remap(&self.column_formatters, sources);
+        self.column_aggregations = remap(&self.column_aggregations, sources);
+        self.column_priorities = remap(&self.column_priorities, sources);
+        self.column_grow_weights = remap(&self.column_grow_weights, sources);
+        self.column_shrink_weights = remap(&self.column_shrink_weights, sources);
+    }
+
+    /// Prepares the rows of the table for rendering (see [`Table::prepare_row`]).
+    ///
+    /// Subtotal rows are inserted after each group, if enabled, and the aggregations of the columns are added as a footer row, if any.
+    /// The placeholder of the table is inserted below the header rows, if there are no body rows.
+    pub(crate) fn prepare_rows(&self) -> Vec<Row> {
+        let column_count = self.column_count();
+        self.generated_rows()
+            .iter()
+            .map(|row| self.prepare_row(row, column_count))
+            .collect()
+    }
+
+    /// Returns the rows of the table, along with the rows generated when rendering:
+    /// group subtotals, the placeholder row, and the aggregate footer row.
+    pub(crate) fn generated_rows(&self) -> Vec<Row> {
+        let column_count = self.column_count();
+        let mut rows = match self.has_group_subtotals {
+            true => {
+                group::insert_subtotals(self.rows.clone(), column_count, &self.column_aggregations)
+            }
+            false => self.rows.clone(),
+        };
+        let has_body_rows = rows.iter().any(|row| row.kind == RowKind::Body);
+        if let Some(placeholder) = self.placeholder.as_ref() {
+            if !has_body_rows {
+                let index = rows
+                    .iter()
+                    .rposition(|row| row.kind == RowKind::Header)
+                    .map_or(0, |index| index + 1);
+                let cell = Cell::new(placeholder)
+                    .with_alignment(Alignment::Center)
+                    .with_column_span(column_count.max(1));
+                rows.insert(index, Row::new().with_kind(RowKind::Footer).with_cell(cell));
+            }
+        }
+        // Without body rows, the footer is only added along with the placeholder, for empty tables to render as such.
+        let has_footer = column_count > 0 && (has_body_rows || self.placeholder.is_some());
+        if !self.column_aggregations.is_empty() && has_footer {
+            rows.push(aggregation::aggregate_rows(
+                &self.rows,
+                column_count,
+                |column_index| self.column_aggregations.get(&column_index),
+                "Total",
+            ));
+        }
+        rows
+    }
+
+    /// Prepares a row for rendering, by formatting the typed values of its cells,
+    /// and by expanding tabs and replacing control characters in their contents.
+    /// Group header rows are also extended to the given number of columns.
+    pub(crate) fn prepare_row(&self, row: &Row, column_count: usize) -> Row {
+        let default_formatter = ValueFormatter::default();
+        let mut row = row.clone();
+        group::fill_group_header(&mut row, column_count);
+        let mut column_index = 0;
+        for cell in row.cells.iter_mut() {
+            if let Some(value) = cell.value.as_ref() {
+                let formatter = self
+                    .column_formatters
+                    .get(&column_index)
+                    .unwrap_or(&default_formatter);
+                cell.content = formatter.format(value);
+            }
+            cell.content = cell::sanitize(
+                &cell.content,
+                self.tab_width,
+                self.control_character_replacement,
+            );
+            column_index += cell.column_span;
+        }
+        row
+    }
+
+    /// Computes the style of the row at the given index.
+    ///
+    /// Zebra stripes alternate over body rows only, `body_index` being the position of the row among them.
+    pub(crate) fn row_style(&self, index: usize, body_index: usize, row: &Row) -> CellStyle {
+        let mut style = CellStyle::new();
+        if let Some((first, second)) = self.zebra_stripes.filter(|_| row.kind == RowKind::Body) {
+            let background = if body_index % 2 == 0 { first } else { second };
+            style = style.with_background(background);
+        }
+        if let Some(styler) = self.row_styler.as_ref() {
+            style = style.merge(styler.style(index, row));
+        }
+        if row.kind == RowKind::GroupHeader {
+            style = style.merge(self.group_header_style);
+        }
+        style
+    }
+
+    /// Computes the styles of every cell in the table, from the row styles and the column rules.
+    ///
+    /// Column rules only apply to body rows.
+    fn cell_styles(&self, rows: &[Row]) -> Vec<Vec<CellStyle>> {
+        let mut column_stats = HashMap::default();
+        for row in rows.iter() {
+            self.update_column_stats(&mut column_stats, row);
+        }
+
+        let mut body_index = 0;
+        rows.iter()
+            .enumerate()
+            .map(|(index, row)| {
+                let row_style = self.row_style(index, body_index, row);
+                if row.kind == RowKind::Body {
+                    body_index += 1;
+                }
+                self.row_cell_styles(row, row_style, &column_stats)
+            })
+            .collect()
+    }
+
+    /// Includes the values of a body row in the statistics of the columns with rules.
+    pub(crate) fn update_column_stats(
+        &self,
+        column_stats: &mut HashMap<usize, ColumnStats>,
+        row: &Row,
+    ) {
+        if row.kind != RowKind::Body {
+            return;
+        }
+        for (column_index, cell) in row.positioned_cells() {
+            if cell.column_span != 1 || !self.column_rules.contains_key(&column_index) {
+                continue;
+            }
+            if let Some(value) = cell.numeric_value() {
+                column_stats.entry(column_index).or_default().add(value);
+            }
+        }
+    }
+
+    /// Computes the styles of the cells of a row, applying the column rules on top of the style of the row.
+    pub(crate) fn row_cell_styles(
+        &self,
+        row: &Row,
+        row_style: CellStyle,
+        column_stats: &HashMap<usize, ColumnStats>,
+    ) -> Vec<CellStyle> {
+        row.positioned_cells()
+            .map(|(column_index, cell)| {
+                let rules = self.column_rules.get(&column_index);
+                let Some(rules) =
+                    rules.filter(|_| row.kind == RowKind::Body && cell.column_span == 1)
+                else {
+                    return row_style;
+                };
+                let stats = column_stats.get(&column_index).copied().unwrap_or_default();
+                rules
+                    .iter()
+                    .filter_map(|rule| rule.evaluate(cell, &stats))
+                    .fold(row_style, CellStyle::merge)
+            })
+            .collect()
+    }
+
+    /// Calculates the widths of the integral and fractional parts of the columns in the table,
+    /// for cells aligned on a decimal separator.
+    pub(crate) fn calculate_decimal_widths(rows: &[Row]) -> Vec<Option<(usize, usize)>> {
+        let mut decimal_widths = Vec::new();
+        for row in rows.iter() {
+            for (column_index, cell) in row.positioned_cells() {
+                if cell.column_span != 1 {
+                    continue;
+                }
+                let Some((integral, fractional)) = cell.decimal_widths() else {
+                    continue;
+                };
+                if decimal_widths.len() <= column_index {
+                    decimal_widths.resize(column_index + 1, None);
+                }
+                let widths = decimal_widths[column_index].get_or_insert((0, 0));
+                widths.0 = widths.0.max(integral);
+                widths.1 = widths.1.max(fractional);
+            }
+        }
+        decimal_widths
+    }
+
+    /// Calculates the widths of the columns in the table.
+    pub(crate) fn calculate_column_widths(
+        &self,
+        rows: &[Row],
+        decimal_widths: &[Option<(usize, usize)>],
+    ) -> Vec<usize> {
+        let mut column_widths = vec![0; count_columns(rows)];
+
+        // First, the columns are sized from the cells which do not span multiple columns.
+        for row in rows.iter() {
+            for (column_index, cell) in row.positioned_cells() {
+                if cell.column_span == 1 {
+                    column_widths[column_index] = column_widths[column_index].max(cell.width());
+                }
+            }
+        }
+
+        for row in rows.iter() {
+            for (column_index, cell) in row.positioned_cells() {
+                if cell.column_span != 1 || !matches!(cell.alignment, Alignment::Decimal(_)) {
+                    continue;
+                }
+                if let Some((integral, fractional)) =
+                    decimal_widths.get(column_index).copied().flatten()
                 {
-                    *column_width = (*column_width).max(if index == 0 {
-                        subcell_width + leftover_width
-                    } else {
-                        subcell_width
-                    });
+                    let padding = if cell.has_padding { 2 } else { 0 };
+                    column_widths[column_index] =
+                        column_widths[column_index].max(integral + fractional + padding);
                 }
-                spanned_columns += cell.column_span;
             }
         }
 
+        // Then, spanning cells only grow the columns they span by the width they lack,
+        // starting from the narrowest spans.
+        let mut spanning_cells: Vec<(usize, &Cell)> = rows
+            .iter()
+            .flat_map(|row| row.positioned_cells())
+            .filter(|(_, cell)| cell.column_span > 1)
+            .collect();
+        spanning_cells.sort_by_key(|(_, cell)| cell.column_span);
+        for (column_index, cell) in spanning_cells.iter().copied() {
+            grow_spanned_columns(&mut column_widths, column_index, cell, |_| usize::MAX);
+        }
+
         for (index, column_width) in column_widths.iter_mut().enumerate() {
-            if let Some(max_width) = self
-                .max_column_widths
-                .get(&index)
-                .copied()
-                .or(self.max_column_width)
-            {
-                *column_width = (*column_width).min(max_width);
-            }
+            let (min_width, max_width) = self.column_width_bounds(index);
+            *column_width = (*column_width).min(max_width).max(min_width);
+        }
+
+        // Constraining the widths might have left spanning cells without enough room,
+        // which is given back to the spanned columns that can still grow.
+        for (column_index, cell) in spanning_cells {
+            grow_spanned_columns(&mut column_widths, column_index, cell, |index| {
+                self.column_width_bounds(index).1
+            });
         }
 
         column_widths
     }
 
+    /// Returns the minimum and maximum widths of the column at the given index.
+    ///
+    /// Fixed widths result in equal bounds, and minimum widths take precedence over maximum widths.
+    /// Bounds are never narrower than a single character, with padding.
+    fn column_width_bounds(&self, index: usize) -> (usize, usize) {
+        let fixed_width = self
+            .fixed_column_widths
+            .get(&index)
+            .copied()
+            .or(self.fixed_column_width);
+        if let Some(fixed_width) = fixed_width {
+            let fixed_width = fixed_width.max(MIN_COLUMN_WIDTH);
+            return (fixed_width, fixed_width);
+        }
+        let min_width = self
+            .min_column_widths
+            .get(&index)
+            .copied()
+            .or(self.min_column_width)
+            .map_or(0, |min_width| min_width.max(MIN_COLUMN_WIDTH));
+        let max_width = self
+            .max_column_widths
+            .get(&index)
+            .copied()
+            .or(self.max_column_width)
+            .map_or(usize::MAX, |max_width| max_width.max(MIN_COLUMN_WIDTH));
+        (min_width, max_width.max(min_width))
+    }
+
+    /// Lists the columns to hide for the table to fit within its maximum width, by order of increasing priority.
+    ///
+    /// Among columns of equal priority, the rightmost ones are hidden first.
+    fn calculate_hidden_columns(&self) -> Vec<usize> {
+        let Some(max_width) = self.max_width else {
+            return Vec::new();
+        };
+        let rows = self.prepare_rows();
+        let decimal_widths = Self::calculate_decimal_widths(&rows);
+        let column_widths = self.calculate_column_widths(&rows, &decimal_widths);
+
+        let mut candidates: Vec<(u32, usize)> = self
+            .column_priorities
+            .iter()
+            .filter(|(index, _)| **index < column_widths.len())
+            .map(|(index, priority)| (*priority, *index))
+            .collect();
+        candidates.sort_by(|a, b| a.0.cmp(&b.0).then(b.1.cmp(&a.1)));
+
+        let mut table_width = total_width(&column_widths);
+        let mut hidden_columns = Vec::new();
+        for (_, index) in candidates {
+            // At least one column is always left visible, to be shrunk instead.
+            if table_width <= max_width || hidden_columns.len() + 1 >= column_widths.len() {
+                break;
+            }
+            table_width -= column_widths[index] + 1;
+            hidden_columns.push(index);
+        }
+        hidden_columns
+    }
+
+    /// Adjusts the widths of the columns for the table to fit within its maximum width, if any.
+    ///
+    /// Narrower tables are widened according to the grow weights of the columns, up to their maximum widths.
+    /// Wider tables are shrunk according to the shrink weights of the columns (or from the widest columns, without shrink weights),
+    /// but columns are never shrunk below their minimum width, nor below the width of a single character (with padding).
+    pub(crate) fn fit_column_widths(&self, column_widths: &mut [usize]) {
+        let Some(max_width) = self.max_width else {
+            return;
+        };
+        let table_width = total_width(column_widths);
+
+        if table_width < max_width {
+            let candidates: Vec<(u64, usize)> = (0..column_widths.len())
+                .map(|index| {
+                    let weight = self.column_grow_weights.get(&index).copied().unwrap_or(0);
+                    let capacity = self.column_width_bounds(index).1 - column_widths[index];
+                    (weight.into(), capacity)
+                })
+                .collect();
+            let shares = split_by_weight(max_width - table_width, &candidates);
+            for (column_width, share) in column_widths.iter_mut().zip(shares) {
+                *column_width += share;
+            }
+            return;
+        }
+
+        let mut excess = table_width - max_width;
+        let min_width = |index: usize| MIN_COLUMN_WIDTH.max(self.column_width_bounds(index).0);
+
+        if !self.column_shrink_weights.is_empty() {
+            let candidates: Vec<(u64, usize)> = (0..column_widths.len())
+                .map(|index| {
+                    let weight = self.column_shrink_weights.get(&index).copied().unwrap_or(1);
+                    let capacity = column_widths[index].saturating_sub(min_width(index));
+                    (u64::from(weight) * column_widths[index] as u64, capacity)
+                })
+                .collect();
+            let shares = split_by_weight(excess, &candidates);
+            for (column_width, share) in column_widths.iter_mut().zip(shares) {
+                *column_width -= share;
+            }
+            return;
+        }
+
+        while excess > 0 {
+            let Some(widest) = column_widths
+                .iter_mut()
+                .enumerate()
+                .filter(|(index, width)| **width > min_width(*index))
+                .map(|(_, width)| width)
+                .max_by_key(|width| **width)
+            else {
+                break;
+            };
+            *widest -= 1;
+            excess -= 1;
+        }
+    }
+
     /// Renders the bottom border of the table.
-    fn render_bottom_border(&self, rendered: &mut String, column_widths: &[usize], last_row: &Row) {
+    pub(crate) fn render_bottom_border(
+        &self,
+        rendered: &mut String,
+        column_widths: &[usize],
+        last_row: &Row,
+    ) {
         let mut spanned_columns = 0;
         rendered.push(self.style.bottom_left_corner);
         for (index, cell) in last_row.cells.iter().enumerate() {
@@ -177,37 +1176,270 @@ impl Table {
         rendered.push(self.style.bottom_right_corner);
     }
 
+    /// Renders a row of the table within the given string, preceded by its top border if it should have one.
+    ///
+    /// Group header rows are always separated from the previous row, unless they have no top border.
+    /// `last_row` is the row rendered just before this one, if any.
+    pub(crate) fn render_row(
+        &self,
+        rendered: &mut String,
+        row: &Row,
+        last_row: Option<&Row>,
+        column_widths: &[usize],
+        decimal_widths: &[Option<(usize, usize)>],
+        cell_styles: &[CellStyle],
+    ) {
+        let has_top_border = match last_row {
+            None => self.has_top_border,
+            Some(_) => self.has_separate_rows || row.kind == RowKind::GroupHeader,
+        };
+        if row.has_top_border && has_top_border {
+            row.render_top_border(rendered, column_widths, &self.style, last_row);
+            rendered.push('\n');
+        }
+        row.render_styled_content(
+            rendered,
+            column_widths,
+            decimal_widths,
+            &self.style,
+            cell_styles,
+            self.has_bidi_reordering,
+        );
+    }
+
     /// Renders the table to a string.
+    ///
+    /// Depending on `expanded`, the table might be rendered as a list of records instead (see [`Table::render_expanded`]).
+    /// Otherwise, columns are hidden by priority, and then shrunk, for the table to fit within its maximum width.
     pub fn render(&self) -> String {
-        let mut rendered = String::new();
-        let column_widths = self.calculate_column_widths();
+        match self.expanded {
+            Expanded::Off => self.render_grid(),
+            Expanded::On => self.render_expanded(),
+            Expanded::Auto => {
+                let available_width = self.max_width.or_else(terminal::terminal_width);
+                match available_width {
+                    Some(available_width) if self.natural_width() > available_width => {
+                        self.render_expanded()
+                    }
+                    _ => self.render_grid(),
+                }
+            }
+        }
+    }
 
+    /// Returns the width the table would have as a grid, without hiding or shrinking any column.
+    fn natural_width(&self) -> usize {
+        let rows = self.prepare_rows();
+        let decimal_widths = Self::calculate_decimal_widths(&rows);
+        total_width(&self.calculate_column_widths(&rows, &decimal_widths))
+    }
+
+    /// Renders the table to a string, as a grid.
+    fn render_grid(&self) -> String {
+        let (table, hidden_column_count) = self.grid_table();
+        let rows = table.prepare_rows();
+        let (column_widths, decimal_widths) = table.layout_columns(&rows);
+        let cell_styles = table.cell_styles(&rows);
+
+        let mut rendered = table.render_rows(&rows, &cell_styles, &column_widths, &decimal_widths);
+        self.render_hidden_columns_marker(&mut rendered, hidden_column_count);
+        rendered
+    }
+
+    /// Returns the table to lay out as a grid, along with the number of columns hidden from it.
+    ///
+    /// Rows are numbered (if enabled), right-to-left tables are mirrored, and the columns to hide for the table to fit within its maximum width are removed.
+    pub(crate) fn grid_table(&self) -> (Cow<'_, Table>, usize) {
+        let mut table = Cow::Borrowed(self);
+        if self.row_numbering.is_some() || self.direction == Direction::RightToLeft {
+            table.to_mut().style_original_rows();
+        }
+        if self.row_numbering.is_some() {
+            table.to_mut().number_rows();
+        }
+        if self.direction == Direction::RightToLeft {
+            let column_count = table.column_count();
+            table.to_mut().mirror(column_count);
+        }
+
+        let hidden_columns = table.calculate_hidden_columns();
+        if !hidden_columns.is_empty() {
+            let column_count = table.column_count();
+            let table = table.to_mut();
+            table.select_columns((0..column_count).filter(|it| !hidden_columns.contains(it)));
+            table.column_priorities.clear();
+        }
+        (table, hidden_columns.len())
+    }
+
+    /// Calculates the widths of the columns of the given prepared rows, fitted to the maximum width of the table,
+    /// along with the widths of the integral and fractional parts of their decimal-aligned cells.
+    pub(crate) fn layout_columns(&self, rows: &[Row]) -> (Vec<usize>, Vec<Option<(usize, usize)>>) {
+        let decimal_widths = Self::calculate_decimal_widths(rows);
+        let mut column_widths = self.calculate_column_widths(rows, &decimal_widths);
+        self.fit_column_widths(&mut column_widths);
+        (column_widths, decimal_widths)
+    }
+
+    /// Renders the given prepared rows, with their cell styles, followed by the bottom border of the table.
+    ///
+    /// Nothing is rendered without rows, not even borders.
+    pub(crate) fn render_rows(
+        &self,
+        rows: &[Row],
+        cell_styles: &[Vec<CellStyle>],
+        column_widths: &[usize],
+        decimal_widths: &[Option<(usize, usize)>],
+    ) -> String {
+        let mut rendered = String::new();
         let mut last_row = None;
-        for (index, row) in self.rows.iter().enumerate() {
+        for (row, cell_styles) in rows.iter().zip(cell_styles.iter()) {
             if last_row.is_some() {
                 rendered.push('\n');
             }
-            if row.has_top_border
-                && ((index == 0 && self.has_top_border) || (index != 0 && self.has_separate_rows))
-            {
-                row.render_top_border(&mut rendered, &column_widths, &self.style, last_row);
-                rendered.push('\n');
-            }
-            row.render_content(&mut rendered, &column_widths, &self.style);
+            self.render_row(
+                &mut rendered,
+                row,
+                last_row,
+                column_widths,
+                decimal_widths,
+                cell_styles,
+            );
             last_row = Some(row);
         }
 
-        if self.has_bottom_border {
+        if let Some(last_row) = rows.last().filter(|_| self.has_bottom_border) {
             rendered.push('\n');
-            self.render_bottom_border(&mut rendered, &column_widths, self.rows.last().unwrap());
+            self.render_bottom_border(&mut rendered, column_widths, last_row);
         }
 
         rendered
     }
+
+    /// Mentions the number of hidden columns below the rendered table, if enabled and if any column is hidden.
+    pub(crate) fn render_hidden_columns_marker(
+        &self,
+        rendered: &mut String,
+        hidden_column_count: usize,
+    ) {
+        if self.has_hidden_columns_marker && hidden_column_count > 0 {
+            let plural = if hidden_column_count == 1 { "" } else { "s" };
+            rendered.push_str(&format!("\n+{hidden_column_count} hidden column{plural}"));
+        }
+    }
+}
+
+/// Mirrors the cells of a row in a table with the given number of columns, resolving their start and end alignments.
+///
+/// Rows spanning fewer columns are completed with empty cells, for their cells to stay in their columns once mirrored.
+pub(crate) fn mirror_row(row: &mut Row, column_count: usize) {
+    group::fill_group_header(row, column_count);
+    let spanned_columns: usize = row.cells.iter().map(|cell| cell.column_span).sum();
+    row.cells.extend(std::iter::repeat_n(
+        Cell::new(""),
+        column_count.saturating_sub(spanned_columns),
+    ));
+    row.cells.reverse();
+    for cell in row.cells.iter_mut() {
+        cell.alignment = match cell.alignment {
+            Alignment::Start => Alignment::Right,
+            Alignment::End => Alignment::Left,
+            alignment => alignment,
+        };
+    }
+}
+
+/// The minimum width of a column when shrinking a table: a single character, with padding.
+const MIN_COLUMN_WIDTH: usize = 3;
+
+/// Returns the total width of a table with the given column widths, including its vertical borders.
+fn total_width(column_widths: &[usize]) -> usize {
+    column_widths.iter().sum::<usize>() + column_widths.len() + 1
+}
+
+/// Grows the columns spanned by a cell (starting at `column_index`) by the width the cell lacks, if any.
+///
+/// The missing width is distributed proportionally to the current widths of the columns (or evenly, if they are all empty),
+/// without growing any column past its maximum width.
+fn grow_spanned_columns(
+    column_widths: &mut [usize],
+    column_index: usize,
+    cell: &Cell,
+    max_width: impl Fn(usize) -> usize,
+) {
+    let spanned = column_index..column_index + cell.column_span;
+    let available = column_widths[spanned.clone()].iter().sum::<usize>() + cell.column_span - 1;
+    let deficit = cell.width().saturating_sub(available);
+
+    let is_empty = column_widths[spanned.clone()]
+        .iter()
+        .all(|width| *width == 0);
+    let candidates: Vec<(u64, usize)> = spanned
+        .clone()
+        .map(|index| {
+            let weight = if is_empty {
+                1
+            } else {
+                column_widths[index] as u64
+            };
+            let capacity = max_width(index).saturating_sub(column_widths[index]);
+            (weight, capacity)
+        })
+        .collect();
+    let shares = split_by_weight(deficit, &candidates);
+    for (index, share) in spanned.zip(shares) {
+        column_widths[index] += share;
+    }
+}
+
+/// Splits an amount between candidates, given as `(weight, capacity)` pairs, proportionally to their weights.
+///
+/// No candidate receives more than its capacity, and the remainders of the divisions go to the first candidates.
+/// Candidates with a weight of 0 receive nothing, so the returned shares might not add up to the whole amount.
+fn split_by_weight(mut amount: usize, candidates: &[(u64, usize)]) -> Vec<usize> {
+    let mut shares = vec![0; candidates.len()];
+    loop {
+        let open: Vec<usize> = (0..candidates.len())
+            .filter(|index| candidates[*index].0 > 0 && shares[*index] < candidates[*index].1)
+            .collect();
+        if amount == 0 || open.is_empty() {
+            return shares;
+        }
+
+        let total_weight: u64 = open.iter().map(|index| candidates[*index].0).sum();
+        let mut distributed = 0;
+        for index in open.iter().copied() {
+            let (weight, capacity) = candidates[index];
+            let share = (amount as u64 * weight / total_weight) as usize;
+            let share = share.min(capacity - shares[index]);
+            shares[index] += share;
+            distributed += share;
+        }
+        if distributed == 0 {
+            for index in open.iter().copied().take(amount) {
+                shares[index] += 1;
+                distributed += 1;
+            }
+        }
+        amount -= distributed;
+    }
+}
+
+/// Returns the number of columns spanned by the widest of the given rows.
+fn count_columns(rows: &[Row]) -> usize {
+    rows.iter()
+        .map(|row| row.cells.iter().map(|cell| cell.column_span).sum::<usize>())
+        .max()
+        .unwrap_or(0)
 }
 
+/// Matches OSC sequences (like hyperlinks), and CSI sequences (like colors and attributes).
 pub(crate) static ANSI_REGEX: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"[\x1b\x9b][\[()#;?]*(?:[0-9]{1,4}(?:;[0-9]{0,4})*)?[0-9A-PRZcf-nqry=><]").unwrap()
+    Regex::new(concat!(
+        r"\x1b\][^\x07\x1b]*(?:\x07|\x1b\\)",
+        r"|[\x1b\x9b][\[()#;?]*(?:[0-9]{1,4}(?:;[0-9]{0,4})*)?[0-9A-PRZcf-nqry=><]",
+    ))
+    .unwrap()
 });
 
 pub(crate) fn visible_width(s: &str) -> usize {
@@ -0,0 +1,196 @@
+use std::io::{self, IsTerminal, Stdout, Write};
+
+use crate::Table;
+
+/// A table redrawn in place every time it is updated, for progress dashboards and the like.
+///
+/// Each update renders the table with [`Table::render`], moves the cursor back to the top of the previous rendering,
+/// and only rewrites the lines that changed, clearing the remaining ones if the table shrunk.
+/// Lines wider than the terminal, or renderings taller than it, cannot be redrawn correctly.
+///
+/// When not redrawing in place (like when the output is not a terminal), every different rendering is simply appended to the output.
+#[derive(Debug)]
+pub struct LiveTable<W: Write> {
+    writer: W,
+    redraws_in_place: bool,
+    lines: Vec<String>,
+}
+
+impl LiveTable<Stdout> {
+    /// Creates a new live table writing to the standard output.
+    ///
+    /// The table is only redrawn in place if the standard output is a terminal.
+    pub fn stdout() -> Self {
+        let stdout = io::stdout();
+        let redraws_in_place = stdout.is_terminal();
+        Self {
+            redraws_in_place,
+            ..Self::new(stdout)
+        }
+    }
+}
+
+impl<W: Write> LiveTable<W> {
+    /// Creates a new live table writing to the given writer, redrawing the table in place.
+    pub fn new(writer: W) -> Self {
+        Self {
+            writer,
+            redraws_in_place: true,
+            lines: Vec::new(),
+        }
+    }
+
+    /// Disables redrawing the table in place, each different rendering being appended to the output instead.
+    pub fn without_redrawing(mut self) -> Self {
+        self.redraws_in_place = false;
+        self
+    }
+
+    /// Renders the given table, replacing the previous rendering.
+    pub fn update(&mut self, table: &Table) -> io::Result<()> {
+        let rendered = table.render();
+        let lines: Vec<String> = rendered.split('\n').map(String::from).collect();
+        if lines == self.lines {
+            return Ok(());
+        }
+
+        let mut output = String::new();
+        if self.redraws_in_place {
+            if !self.lines.is_empty() {
+                // Moves to the beginning of the first line of the previous rendering.
+                output.push_str(&format!("\x1b[{}F", self.lines.len()));
+            }
+            let mut unchanged_lines = 0;
+            for (index, line) in lines.iter().enumerate() {
+                if self.lines.get(index) == Some(line) {
+                    unchanged_lines += 1;
+                    continue;
+                }
+                if unchanged_lines > 0 {
+                    // Moves to the beginning of the line, skipping over the unchanged ones.
+                    output.push_str(&format!("\x1b[{unchanged_lines}E"));
+                    unchanged_lines = 0;
+                }
+                output.push_str("\x1b[2K");
+                output.push_str(line);
+                output.push('\n');
+            }
+            if unchanged_lines > 0 {
+                output.push_str(&format!("\x1b[{unchanged_lines}E"));
+            }
+            if lines.len() < self.lines.len() {
+                // Clears the remaining lines of the previous rendering.
+                output.push_str("\x1b[J");
+            }
+        } else {
+            output.push_str(&rendered);
+            output.push('\n');
+        }
+
+        self.writer.write_all(output.as_bytes())?;
+        self.writer.flush()?;
+        self.lines = lines;
+        Ok(())
+    }
+
+    /// Returns the writer of the table.
+    pub fn into_writer(self) -> W {
+        self.writer
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Cell, Row};
+
+    /// Creates a table with a single column holding the given contents.
+    fn table(contents: &[&str]) -> Table {
+        Table::new().with_rows(
+            contents
+                .iter()
+                .map(|content| Row::new().with_cell(Cell::new(content))),
+        )
+    }
+
+    /// Returns the lines of the rendering of the given table.
+    fn lines(table: &Table) -> Vec<String> {
+        table.render().split('\n').map(String::from).collect()
+    }
+
+    /// Returns the output written by the given live table since the last call, as a string.
+    fn take_output(live_table: &mut LiveTable<Vec<u8>>) -> String {
+        String::from_utf8(std::mem::take(&mut live_table.writer)).unwrap()
+    }
+
+    #[test]
+    fn rewrites_only_the_changed_lines() {
+        let mut live_table = LiveTable::new(Vec::new());
+        let before = table(&["a", "b", "c"]);
+        live_table.update(&before).unwrap();
+        let written: String = lines(&before)
+            .iter()
+            .map(|line| format!("\x1b[2K{line}\n"))
+            .collect();
+        assert_eq!(take_output(&mut live_table), written);
+
+        // Only the line of the second row changes, out of the 7 lines of the table.
+        let after = table(&["a", "x", "c"]);
+        live_table.update(&after).unwrap();
+        let changed_line = &lines(&after)[3];
+        assert_eq!(
+            take_output(&mut live_table),
+            format!("\x1b[7F\x1b[3E\x1b[2K{changed_line}\n\x1b[3E")
+        );
+
+        live_table.update(&after).unwrap();
+        assert_eq!(take_output(&mut live_table), "");
+    }
+
+    #[test]
+    fn writes_the_new_lines_of_a_growing_table() {
+        let mut live_table = LiveTable::new(Vec::new());
+        live_table.update(&table(&["a"])).unwrap();
+        take_output(&mut live_table);
+
+        // The bottom border becomes a separator, followed by the new row and the bottom border.
+        let after = table(&["a", "b"]);
+        live_table.update(&after).unwrap();
+        let written: String = lines(&after)[2..]
+            .iter()
+            .map(|line| format!("\x1b[2K{line}\n"))
+            .collect();
+        assert_eq!(
+            take_output(&mut live_table),
+            format!("\x1b[3F\x1b[2E{written}")
+        );
+    }
+
+    #[test]
+    fn clears_the_remaining_lines_of_a_shrinking_table() {
+        let mut live_table = LiveTable::new(Vec::new());
+        live_table.update(&table(&["a", "b"])).unwrap();
+        take_output(&mut live_table);
+
+        let after = table(&["a"]);
+        live_table.update(&after).unwrap();
+        let bottom_border = &lines(&after)[2];
+        assert_eq!(
+            take_output(&mut live_table),
+            format!("\x1b[5F\x1b[2E\x1b[2K{bottom_border}\n\x1b[J")
+        );
+    }
+
+    #[test]
+    fn appends_renderings_without_redrawing() {
+        let mut live_table = LiveTable::new(Vec::new()).without_redrawing();
+        let (first, second) = (table(&["a"]), table(&["b"]));
+        live_table.update(&first).unwrap();
+        live_table.update(&first).unwrap();
+        live_table.update(&second).unwrap();
+        assert_eq!(
+            String::from_utf8(live_table.into_writer()).unwrap(),
+            format!("{}\n{}\n", first.render(), second.render())
+        );
+    }
+}
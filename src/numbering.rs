@@ -0,0 +1,79 @@
+use crate::cell::{Alignment, Cell};
+use crate::column;
+use crate::row::RowKind;
+use crate::Table;
+
+/// The settings of the column numbering the body rows of a table.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RowNumbering {
+    /// The number of the first body row.
+    pub start: usize,
+    /// The content of the column's cell in the last header row.
+    pub header: String,
+    /// The alignment of the column's cells.
+    pub alignment: Alignment,
+}
+
+impl Default for RowNumbering {
+    fn default() -> Self {
+        Self {
+            start: 1,
+            header: String::from("#"),
+            alignment: Alignment::Right,
+        }
+    }
+}
+
+impl Table {
+    /// Prepends the row numbering column to the table, if enabled.
+    ///
+    /// Body rows are numbered in order, and the last header row holds the header of the column.
+    /// Group header rows, and other rows starting with a spanning cell, have their first cell grow to cover the column instead.
+    ///
+    /// The generated rows (like subtotals) are added to the table beforehand, so that their labels stay out of the column.
+    pub(crate) fn number_rows(&mut self) {
+        let Some(numbering) = self.row_numbering.take() else {
+            return;
+        };
+        self.rows = self.generated_rows();
+        self.has_group_subtotals = false;
+        self.placeholder = None;
+        self.column_aggregations.clear();
+
+        let column_count = self.column_count();
+        let last_header = self
+            .rows
+            .iter()
+            .rposition(|row| row.kind == RowKind::Header);
+
+        let mut number = numbering.start;
+        for (index, row) in self.rows.iter_mut().enumerate() {
+            let content = match row.kind {
+                RowKind::Body => {
+                    number += 1;
+                    (number - 1).to_string()
+                }
+                RowKind::Header if Some(index) == last_header => numbering.header.clone(),
+                _ => String::new(),
+            };
+            let first_cell = row.cells.first_mut();
+            match first_cell {
+                Some(cell)
+                    if row.kind == RowKind::GroupHeader
+                        || (row.kind != RowKind::Body && cell.column_span > 1) =>
+                {
+                    cell.column_span += 1;
+                }
+                _ => {
+                    let cell = Cell::new(content).with_alignment(numbering.alignment);
+                    column::insert_cell(row, 0, cell);
+                }
+            }
+        }
+
+        let sources: Vec<Option<usize>> = std::iter::once(None)
+            .chain((0..column_count).map(Some))
+            .collect();
+        self.remap_column_settings(&sources);
+    }
+}
@@ -0,0 +1,51 @@
+use std::io::{self, IsTerminal, Write};
+use std::process::{Command, Stdio};
+
+use crate::terminal;
+use crate::Table;
+
+/// The pager used when the `PAGER` environment variable is unset, scrolling horizontally and keeping ANSI colors.
+const DEFAULT_PAGER: &str = "less -SR";
+
+impl Table {
+    /// Prints the table to the standard output, through a pager if it is taller than the terminal.
+    ///
+    /// The pager is taken from the `PAGER` environment variable (defaulting to `less -SR`).
+    /// The table is printed directly when the standard output is not a terminal, or when the pager cannot be started.
+    pub fn print_paged(&self) -> io::Result<()> {
+        let rendered = self.render();
+        let mut stdout = io::stdout();
+
+        let is_taller =
+            terminal::terminal_height().is_some_and(|height| rendered.lines().count() >= height);
+        if !stdout.is_terminal() || !is_taller {
+            return writeln!(stdout, "{rendered}");
+        }
+
+        let pager = std::env::var("PAGER")
+            .ok()
+            .filter(|pager| !pager.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_PAGER.to_string());
+        let mut arguments = pager.split_whitespace();
+        let Some(program) = arguments.next() else {
+            return writeln!(stdout, "{rendered}");
+        };
+        let child = Command::new(program)
+            .args(arguments)
+            .stdin(Stdio::piped())
+            .spawn();
+        let Ok(mut child) = child else {
+            return writeln!(stdout, "{rendered}");
+        };
+
+        if let Some(mut stdin) = child.stdin.take() {
+            match writeln!(stdin, "{rendered}") {
+                // The pager was closed before reading the whole table.
+                Err(error) if error.kind() == io::ErrorKind::BrokenPipe => {}
+                result => result?,
+            }
+        }
+        child.wait()?;
+        Ok(())
+    }
+}
@@ -0,0 +1,129 @@
+use crate::cell_style::CellStyle;
+use crate::row::{Row, RowKind};
+use crate::Table;
+
+/// The size limit of a page.
+#[derive(Debug, Clone, Copy)]
+enum PageSize {
+    /// At most this many rows, besides the header rows.
+    Rows(usize),
+    /// At most this many lines.
+    Height(usize),
+}
+
+impl Table {
+    /// Renders the table as a grid split into pages of at most `rows_per_page` rows each, besides the header rows.
+    ///
+    /// See [`Table::paginate_by_height`] for how pages are rendered.
+    pub fn paginate(&self, rows_per_page: usize) -> impl Iterator<Item = String> + '_ {
+        self.render_pages(PageSize::Rows(rows_per_page))
+    }
+
+    /// Renders the table as a grid split into pages of at most `height` lines each.
+    ///
+    /// Every page repeats the header rows of the table, and uses the same column widths, so that pages line up with each other.
+    /// A page always holds at least one row, even if it makes the page taller than `height`.
+    /// With page numbers, a `Page 2/7` line is added below each page, and counts towards its height.
+    pub fn paginate_by_height(&self, height: usize) -> impl Iterator<Item = String> + '_ {
+        self.render_pages(PageSize::Height(height))
+    }
+
+    /// Renders the table as a grid split into pages of the given size.
+    fn render_pages(&self, page_size: PageSize) -> impl Iterator<Item = String> + '_ {
+        let (table, hidden_column_count) = self.grid_table();
+        let rows = table.prepare_rows();
+        let (column_widths, decimal_widths) = table.layout_columns(&rows);
+        let cell_styles = table.cell_styles(&rows);
+
+        let (headers, mut others): (Vec<_>, Vec<_>) = rows
+            .into_iter()
+            .zip(cell_styles)
+            .partition(|(row, _)| row.kind == RowKind::Header);
+
+        let row_height = |row: &Row, last_row: Option<&Row>| {
+            let mut rendered = String::new();
+            table.render_row(
+                &mut rendered,
+                row,
+                last_row,
+                &column_widths,
+                &decimal_widths,
+                &[],
+            );
+            rendered.lines().count()
+        };
+        let mut fixed_height = usize::from(table.has_bottom_border)
+            + usize::from(self.has_page_numbers)
+            + usize::from(self.has_hidden_columns_marker && hidden_column_count > 0);
+        let mut last_header = None;
+        for (row, _) in headers.iter() {
+            fixed_height += row_height(row, last_header);
+            last_header = Some(row);
+        }
+
+        // Each page starts like the first one, with the top border of the first row following the header rows, if it has one.
+        let has_top_border = others.first().is_some_and(|(row, _)| row.has_top_border);
+        let heights: Vec<(usize, usize)> = others
+            .iter()
+            .enumerate()
+            .map(|(index, (row, _))| {
+                let first_row = Row {
+                    has_top_border,
+                    ..row.clone()
+                };
+                let last_row = index.checked_sub(1).map(|index| &others[index].0);
+                (
+                    row_height(&first_row, last_header),
+                    row_height(row, last_row.or(last_header)),
+                )
+            })
+            .collect();
+
+        let mut page_sizes = Vec::new();
+        let mut start = 0;
+        while start < others.len() {
+            let mut page_height = fixed_height + heights[start].0;
+            let mut end = start + 1;
+            while end < others.len() {
+                let fits = match page_size {
+                    PageSize::Rows(rows_per_page) => end - start < rows_per_page,
+                    PageSize::Height(height) => page_height + heights[end].1 <= height,
+                };
+                if !fits {
+                    break;
+                }
+                page_height += heights[end].1;
+                end += 1;
+            }
+            page_sizes.push(end - start);
+            start = end;
+        }
+        if page_sizes.is_empty() {
+            page_sizes.push(0);
+        }
+
+        let page_count = page_sizes.len();
+        page_sizes
+            .into_iter()
+            .enumerate()
+            .map(move |(index, page_size)| {
+                let page: Vec<(Row, Vec<CellStyle>)> = others.drain(..page_size).collect();
+                let (mut rows, cell_styles): (Vec<Row>, Vec<Vec<CellStyle>>) =
+                    headers.iter().cloned().chain(page).unzip();
+                if let Some(row) = rows.get_mut(headers.len()) {
+                    row.has_top_border = has_top_border;
+                }
+
+                let mut rendered =
+                    table.render_rows(&rows, &cell_styles, &column_widths, &decimal_widths);
+                self.render_hidden_columns_marker(&mut rendered, hidden_column_count);
+                if self.has_page_numbers {
+                    if !rendered.is_empty() {
+                        rendered.push('\n');
+                    }
+                    rendered.push_str(&format!("Page {}/{}", index + 1, page_count));
+                }
+                rendered
+            })
+    }
+}
@@ -1,8 +1,26 @@
+use std::borrow::Cow;
 use std::cmp::Ordering;
 
-use crate::cell::{Alignment, Cell};
+use crate::bidi;
+use crate::cell::{self, Alignment, Cell};
+use crate::cell_style::CellStyle;
 use crate::style::Style;
 
+/// The role of a row within a table.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RowKind {
+    /// A header row, describing the columns.
+    Header,
+    /// A regular row, holding data.
+    Body,
+    /// A footer row, summarizing the data.
+    Footer,
+    /// A section header row, titling the group of body rows following it.
+    ///
+    /// Its last cell spans the remaining columns of the table.
+    GroupHeader,
+}
+
 /// A row within a table.
 #[derive(Debug, Clone, PartialEq)]
 pub struct Row {
@@ -10,6 +28,10 @@ pub struct Row {
     pub cells: Vec<Cell>,
     /// Whether the row should have a top border.
     pub has_top_border: bool,
+    /// The role of the row within the table.
+    ///
+    /// Only body rows are affected by data operations (like sorting), zebra stripes and column rules.
+    pub kind: RowKind,
 }
 
 impl Default for Row {
@@ -24,6 +46,7 @@ impl Row {
         Self {
             cells: Vec::default(),
             has_top_border: true,
+            kind: RowKind::Body,
         }
     }
 
@@ -45,8 +68,49 @@ impl Row {
         self
     }
 
+    /// Sets the role of the row within the table.
+    pub fn with_kind(mut self, kind: RowKind) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// Returns the cell occupying the given column, if any.
+    pub fn cell_at_column(&self, column_index: usize) -> Option<&Cell> {
+        self.positioned_cells()
+            .find(|(position, cell)| {
+                (*position..*position + cell.column_span).contains(&column_index)
+            })
+            .map(|(_, cell)| cell)
+    }
+
+    /// Returns the cells of the row, along with the index of the first column they occupy.
+    pub(crate) fn positioned_cells(&self) -> impl Iterator<Item = (usize, &Cell)> {
+        self.cells.iter().scan(0, |column_index, cell| {
+            let position = *column_index;
+            *column_index += cell.column_span;
+            Some((position, cell))
+        })
+    }
+
     /// Renders the contents of the row within the given string.
     pub fn render_content(&self, rendered: &mut String, column_widths: &[usize], style: &Style) {
+        self.render_styled_content(rendered, column_widths, &[], style, &[], false);
+    }
+
+    /// Renders the contents of the row within the given string, painting each cell with its matching style in `cell_styles`.
+    ///
+    /// Cells without a matching style are rendered as-is.
+    /// Cells aligned on a decimal separator use the integral and fractional widths of their column from `decimal_widths`.
+    /// If `is_reordered` is set, each line of the cells is reordered for display with the Unicode Bidirectional Algorithm.
+    pub(crate) fn render_styled_content(
+        &self,
+        rendered: &mut String,
+        column_widths: &[usize],
+        decimal_widths: &[Option<(usize, usize)>],
+        style: &Style,
+        cell_styles: &[CellStyle],
+        is_reordered: bool,
+    ) {
         let mut row_height = 0;
         let mut spanned_columns = 0;
         let mut wrapped_contents = Vec::new();
@@ -71,7 +135,9 @@ impl Row {
                 rendered.push('\n');
             }
             spanned_columns = 0;
-            for (cell, wrapped_content) in self.cells.iter().zip(wrapped_contents.iter()) {
+            for (cell_index, (cell, wrapped_content)) in
+                self.cells.iter().zip(wrapped_contents.iter()).enumerate()
+            {
                 let width: usize = column_widths
                     .iter()
                     .copied()
@@ -83,34 +149,78 @@ impl Row {
 
                 rendered.push(style.vertical);
 
+                let mut segment = String::new();
                 if cell.has_padding {
-                    rendered.push(' ');
+                    segment.push(' ');
                 }
 
                 if let Some(line) = wrapped_content.get(line_index) {
                     let line_width = crate::visible_width(line);
+                    let line = if is_reordered {
+                        bidi::reorder_line(line)
+                    } else {
+                        Cow::Borrowed(line.as_str())
+                    };
+                    let line = &*cell.linked_line(&line);
                     match cell.alignment {
-                        Alignment::Left => {
-                            rendered.push_str(line);
-                            rendered.extend(std::iter::repeat_n(' ', width - line_width));
+                        Alignment::Left | Alignment::Start => {
+                            segment.push_str(line);
+                            segment.extend(std::iter::repeat_n(' ', width - line_width));
                         }
                         Alignment::Center => {
                             let padding = (width - line_width) / 2;
-                            rendered.extend(std::iter::repeat_n(' ', padding));
-                            rendered.push_str(line);
-                            rendered.extend(std::iter::repeat_n(' ', width - line_width - padding));
+                            segment.extend(std::iter::repeat_n(' ', padding));
+                            segment.push_str(line);
+                            segment.extend(std::iter::repeat_n(' ', width - line_width - padding));
+                        }
+                        Alignment::Right | Alignment::End => {
+                            segment.extend(std::iter::repeat_n(' ', width - line_width));
+                            segment.push_str(line);
                         }
-                        Alignment::Right => {
-                            rendered.extend(std::iter::repeat_n(' ', width - line_width));
-                            rendered.push_str(line);
+                        Alignment::Decimal(separator) => {
+                            let (integral, fractional) = cell::split_decimal(line, separator);
+                            let integral = crate::visible_width(integral);
+                            let fractional = crate::visible_width(fractional);
+                            let decimal_widths = decimal_widths
+                                .get(spanned_columns)
+                                .copied()
+                                .flatten()
+                                .filter(|(integral_width, fractional_width)| {
+                                    cell.column_span == 1
+                                        && integral_width + fractional_width <= width
+                                        && integral <= *integral_width
+                                        && fractional <= *fractional_width
+                                });
+                            match decimal_widths {
+                                Some((integral_width, fractional_width)) => {
+                                    let integral_padding = integral_width - integral;
+                                    let fractional_padding = fractional_width - fractional;
+                                    segment.extend(std::iter::repeat_n(
+                                        ' ',
+                                        width - integral_width - fractional_width
+                                            + integral_padding,
+                                    ));
+                                    segment.push_str(line);
+                                    segment.extend(std::iter::repeat_n(' ', fractional_padding));
+                                }
+                                None => {
+                                    segment.extend(std::iter::repeat_n(' ', width - line_width));
+                                    segment.push_str(line);
+                                }
+                            }
                         }
                     }
                 } else {
-                    rendered.extend(std::iter::repeat_n(' ', width));
+                    segment.extend(std::iter::repeat_n(' ', width));
                 }
 
                 if cell.has_padding {
-                    rendered.push(' ');
+                    segment.push(' ');
+                }
+
+                match cell_styles.get(cell_index) {
+                    Some(cell_style) => rendered.push_str(&cell_style.paint(&segment)),
+                    None => rendered.push_str(&segment),
                 }
 
                 spanned_columns += cell.column_span;
@@ -0,0 +1,119 @@
+use std::fmt;
+use std::sync::Arc;
+
+use crate::cell::Cell;
+use crate::cell_style::{CellStyle, Color};
+
+type CellPredicateFn = dyn Fn(&Cell) -> bool + Send + Sync;
+
+/// A predicate over the cells of a column.
+#[derive(Clone)]
+pub struct CellPredicate(Arc<CellPredicateFn>);
+
+impl CellPredicate {
+    /// Creates a new cell predicate from the given function.
+    pub fn new(predicate: impl Fn(&Cell) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(predicate))
+    }
+
+    /// Evaluates the predicate against the given cell.
+    pub fn test(&self, cell: &Cell) -> bool {
+        (self.0)(cell)
+    }
+}
+
+impl fmt::Debug for CellPredicate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CellPredicate(..)")
+    }
+}
+
+impl PartialEq for CellPredicate {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// A conditional formatting rule, applied to the cells of a column when rendering.
+///
+/// Numeric rules only consider cells holding a number, optionally followed by a unit (see [`Cell::numeric_value`]).
+/// Cells spanning multiple columns are never matched by rules.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Rule {
+    /// Applies the style to cells matching the predicate.
+    Predicate(CellPredicate, CellStyle),
+    /// Applies the style to cells with a value strictly greater than the threshold.
+    GreaterThan(f64, CellStyle),
+    /// Applies the style to cells with a value strictly less than the threshold.
+    LessThan(f64, CellStyle),
+    /// Applies the style to cells with a value within the (inclusive) range.
+    Between(f64, f64, CellStyle),
+    /// Applies the style to the cells holding the maximum value of the column.
+    Maximum(CellStyle),
+    /// Applies the style to the cells holding the minimum value of the column.
+    Minimum(CellStyle),
+    /// Sets the background of cells to a color interpolated between the two given RGB colors,
+    /// according to where their value sits between the minimum and maximum values of the column.
+    Heatmap((u8, u8, u8), (u8, u8, u8)),
+}
+
+impl Rule {
+    /// Creates a rule applying the style to cells matching the predicate.
+    pub fn predicate(
+        predicate: impl Fn(&Cell) -> bool + Send + Sync + 'static,
+        style: CellStyle,
+    ) -> Self {
+        Rule::Predicate(CellPredicate::new(predicate), style)
+    }
+
+    /// Returns the style this rule applies to the given cell, if any.
+    pub(crate) fn evaluate(&self, cell: &Cell, stats: &ColumnStats) -> Option<CellStyle> {
+        if let Rule::Predicate(predicate, style) = self {
+            return predicate.test(cell).then_some(*style);
+        }
+
+        let value = cell.numeric_value()?;
+        match *self {
+            Rule::Predicate(..) => None,
+            Rule::GreaterThan(threshold, style) => (value > threshold).then_some(style),
+            Rule::LessThan(threshold, style) => (value < threshold).then_some(style),
+            Rule::Between(min, max, style) => (min <= value && value <= max).then_some(style),
+            Rule::Maximum(style) => (Some(value) == stats.max).then_some(style),
+            Rule::Minimum(style) => (Some(value) == stats.min).then_some(style),
+            Rule::Heatmap(low, high) => {
+                let (min, max) = stats.min.zip(stats.max)?;
+                let ratio = if max > min {
+                    (value - min) / (max - min)
+                } else {
+                    0.0
+                };
+                let interpolate = |low: u8, high: u8| {
+                    (low as f64 + (high as f64 - low as f64) * ratio).round() as u8
+                };
+                let color = Color::Rgb(
+                    interpolate(low.0, high.0),
+                    interpolate(low.1, high.1),
+                    interpolate(low.2, high.2),
+                );
+                Some(CellStyle::new().with_background(color))
+            }
+        }
+    }
+}
+
+/// The numeric statistics of a column, used to evaluate rules.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct ColumnStats {
+    /// The minimum value of the column.
+    pub min: Option<f64>,
+    /// The maximum value of the column.
+    pub max: Option<f64>,
+}
+
+impl ColumnStats {
+    /// Includes the given value in the statistics.
+    pub fn add(&mut self, value: f64) {
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+    }
+}
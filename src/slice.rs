@@ -0,0 +1,72 @@
+use crate::{terminal, Table};
+
+impl Table {
+    /// Renders the table as a stack of slices, each holding as many columns as fit within the maximum width of the table
+    /// (or the width of the terminal, if unset), for very wide tables to be read without scrolling horizontally.
+    ///
+    /// The first `frozen_column_count` columns are repeated in every slice, like frozen panes in a spreadsheet.
+    /// Each slice is a complete table, whose columns keep the widths they would have without slicing
+    /// (unless they are too wide to ever fit), and no column is hidden.
+    pub fn render_sliced(&self, frozen_column_count: usize) -> String {
+        if self.row_numbering.is_some() {
+            // The numbering column is frozen along with the first columns.
+            let mut table = self.clone();
+            table.style_original_rows();
+            table.number_rows();
+            return table.render_sliced(frozen_column_count + 1);
+        }
+
+        let column_count = self.column_count();
+        let available_width = self.max_width.or_else(terminal::terminal_width);
+        let Some(available_width) = available_width.filter(|_| frozen_column_count < column_count)
+        else {
+            return self.render_grid();
+        };
+
+        let rows = self.prepare_rows();
+        let decimal_widths = Self::calculate_decimal_widths(&rows);
+        let mut column_widths = self.calculate_column_widths(&rows, &decimal_widths);
+
+        let frozen_width = column_widths[..frozen_column_count]
+            .iter()
+            .map(|width| width + 1)
+            .sum::<usize>();
+        let max_column_width = available_width
+            .saturating_sub(frozen_width + 2)
+            .max(crate::MIN_COLUMN_WIDTH);
+        for width in column_widths[frozen_column_count..].iter_mut() {
+            *width = (*width).min(max_column_width);
+        }
+
+        let mut slices = Vec::new();
+        let mut start = frozen_column_count;
+        while start < column_count {
+            let mut slice_width = frozen_width + column_widths[start] + 2;
+            let mut end = start + 1;
+            while end < column_count {
+                let column_width = column_widths[end] + 1;
+                if slice_width + column_width > available_width {
+                    break;
+                }
+                slice_width += column_width;
+                end += 1;
+            }
+
+            let columns: Vec<usize> = (0..frozen_column_count).chain(start..end).collect();
+            let mut table = self.clone();
+            table.select_columns(columns.iter().copied());
+            table.fixed_column_widths = columns
+                .iter()
+                .enumerate()
+                .map(|(index, column)| (index, column_widths[*column]))
+                .collect();
+            table.max_width = None;
+            table.column_priorities.clear();
+            slices.push(table.render_grid());
+
+            start = end;
+        }
+
+        slices.join("\n")
+    }
+}
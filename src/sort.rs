@@ -0,0 +1,107 @@
+use std::cmp::Ordering;
+
+use crate::cell::Cell;
+use crate::value::Value;
+
+/// The order in which rows are sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Order {
+    /// From the smallest to the largest value.
+    Ascending,
+    /// From the largest to the smallest value.
+    Descending,
+}
+
+/// Compares two cells for sorting, in the given order.
+///
+/// Cells holding typed values are compared by value, while the others are compared naturally by content.
+/// Missing cells and null values are always sorted last.
+pub(crate) fn compare_cells(a: Option<&Cell>, b: Option<&Cell>, order: Order) -> Ordering {
+    let is_null = |cell: Option<&Cell>| cell.is_none_or(|cell| cell.value == Some(Value::Null));
+    match (is_null(a), is_null(b)) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Greater,
+        (false, true) => return Ordering::Less,
+        (false, false) => {}
+    }
+
+    let (a, b) = (a.unwrap(), b.unwrap());
+    let ordering = match (a.value, b.value) {
+        (Some(a), Some(b)) => compare_values(a, b),
+        // Typed values are sorted before untyped contents.
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => compare_naturally(
+            &crate::ANSI_REGEX.replace_all(&a.content, ""),
+            &crate::ANSI_REGEX.replace_all(&b.content, ""),
+        ),
+    };
+
+    match order {
+        Order::Ascending => ordering,
+        Order::Descending => ordering.reverse(),
+    }
+}
+
+/// Compares two non-null values.
+///
+/// Values of different kinds are ranked by kind (numbers, durations, timestamps, sizes, then booleans),
+/// and values of the same kind are compared numerically, `NaN` being sorted after every other float.
+fn compare_values(a: Value, b: Value) -> Ordering {
+    let rank = |value: &Value| match value {
+        Value::Integer(_) | Value::Float(_) | Value::Decimal(..) => 0,
+        Value::Duration(_) => 1,
+        Value::Timestamp(_) => 2,
+        Value::Bytes(_) => 3,
+        Value::Bool(_) => 4,
+        Value::Null => 5,
+    };
+    rank(&a).cmp(&rank(&b)).then_with(|| match (a, b) {
+        (Value::Bool(a), Value::Bool(b)) => a.cmp(&b),
+        (a, b) => match (a.as_f64(), b.as_f64()) {
+            (Some(a), Some(b)) => a.total_cmp(&b),
+            _ => Ordering::Equal,
+        },
+    })
+}
+
+/// Compares two strings in natural order, where runs of digits are compared by their numeric value (so "file2" comes before "file10").
+///
+/// Letters are compared case-insensitively, falling back to a case-sensitive comparison to break ties.
+pub(crate) fn compare_naturally(a: &str, b: &str) -> Ordering {
+    let mut a_chars = a.chars().peekable();
+    let mut b_chars = b.chars().peekable();
+    loop {
+        match (a_chars.peek().copied(), b_chars.peek().copied()) {
+            (None, None) => return a.cmp(b),
+            (None, Some(_)) => return Ordering::Less,
+            (Some(_), None) => return Ordering::Greater,
+            (Some(a_char), Some(b_char)) if a_char.is_ascii_digit() && b_char.is_ascii_digit() => {
+                let a_digits = take_digits(&mut a_chars);
+                let b_digits = take_digits(&mut b_chars);
+                let a_number = a_digits.trim_start_matches('0');
+                let b_number = b_digits.trim_start_matches('0');
+                let ordering = a_number
+                    .len()
+                    .cmp(&b_number.len())
+                    .then_with(|| a_number.cmp(b_number));
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+            }
+            (Some(a_char), Some(b_char)) => {
+                let ordering = a_char.to_lowercase().cmp(b_char.to_lowercase());
+                if ordering != Ordering::Equal {
+                    return ordering;
+                }
+                a_chars.next();
+                b_chars.next();
+            }
+        }
+    }
+}
+
+/// Consumes the leading run of ASCII digits from the given iterator.
+fn take_digits(chars: &mut std::iter::Peekable<std::str::Chars<'_>>) -> String {
+    std::iter::from_fn(|| chars.next_if(char::is_ascii_digit)).collect()
+}
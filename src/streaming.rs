@@ -0,0 +1,282 @@
+use std::collections::HashMap;
+use std::io::{self, Write};
+
+use crate::bidi::Direction;
+use crate::column;
+use crate::row::{Row, RowKind};
+use crate::rule::ColumnStats;
+use crate::Table;
+
+/// How the widths of the columns of a [`StreamingTable`] are decided.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum ColumnWidths {
+    /// The widths of the columns (including padding), in order.
+    ///
+    /// Columns are never narrower than a single character, with padding.
+    Fixed(Vec<usize>),
+    /// Widths inferred from the initial rows of the table and the given number of body rows.
+    ///
+    /// The rows are held back until enough body rows have been pushed (or until the table is finished),
+    /// and until at least one row has been pushed if the table has no initial rows.
+    Inferred(usize),
+}
+
+/// A table writing its rows as soon as they are pushed, for data that cannot be held up front (like tailing logs).
+///
+/// The table takes its settings (style, borders, formatters, rules, direction...) from a template [`Table`],
+/// whose rows (typically the header rows) are written first.
+/// Column rules comparing cells to the rest of their column (like [`Rule::Maximum`](crate::Rule::Maximum)) only consider the rows written so far,
+/// and decimal alignment only considers the rows used to infer the widths of the columns.
+/// The table is never rendered as records, and none of its columns are hidden.
+///
+/// The bottom border of the table is written by [`StreamingTable::finish`], or when the table is dropped.
+#[derive(Debug)]
+pub struct StreamingTable<W: Write> {
+    table: Table,
+    writer: W,
+    column_widths: ColumnWidths,
+    layout: Option<Layout>,
+    pending_rows: Vec<Row>,
+    last_row: Option<Row>,
+    row_count: usize,
+    body_row_count: usize,
+    column_stats: HashMap<usize, ColumnStats>,
+    is_finished: bool,
+}
+
+/// The layout of a streaming table, once its column widths are known.
+#[derive(Debug)]
+struct Layout {
+    column_count: usize,
+    column_widths: Vec<usize>,
+    decimal_widths: Vec<Option<(usize, usize)>>,
+    is_mirrored: bool,
+    /// The table before mirroring, preparing the rows seen by the row styler, if the table is mirrored and has one.
+    original_table: Option<Table>,
+}
+
+impl<W: Write> StreamingTable<W> {
+    /// Creates a new streaming table writing to the given writer, with the settings and the initial rows of the given table.
+    ///
+    /// With fixed column widths, the top border and the initial rows are written immediately.
+    pub fn new(table: Table, column_widths: ColumnWidths, writer: W) -> io::Result<Self> {
+        let mut streaming_table = Self {
+            table: Table {
+                rows: Vec::new(),
+                ..table
+            },
+            writer,
+            column_widths,
+            layout: None,
+            pending_rows: table.rows,
+            last_row: None,
+            row_count: 0,
+            body_row_count: 0,
+            column_stats: HashMap::default(),
+            is_finished: false,
+        };
+        if streaming_table.has_enough_rows() {
+            streaming_table.write_pending_rows()?;
+        }
+        Ok(streaming_table)
+    }
+
+    /// Adds a row to the table, writing it immediately if the widths of the columns are known.
+    ///
+    /// Cells beyond the columns of the table are discarded.
+    pub fn push_row(&mut self, row: Row) -> io::Result<()> {
+        if self.layout.is_none() {
+            self.pending_rows.push(row);
+            if self.has_enough_rows() {
+                self.write_pending_rows()?;
+            }
+            return Ok(());
+        }
+        self.write_row(row)
+    }
+
+    /// Writes the remaining rows and the bottom border of the table, and flushes the writer.
+    pub fn finish(mut self) -> io::Result<()> {
+        self.finish_table()
+    }
+
+    /// Returns whether enough rows have been pushed to decide the widths of the columns.
+    ///
+    /// Inferring widths requires at least one row, to know the number of columns.
+    fn has_enough_rows(&self) -> bool {
+        match self.column_widths {
+            ColumnWidths::Fixed(_) => true,
+            ColumnWidths::Inferred(_) if self.pending_rows.is_empty() => false,
+            ColumnWidths::Inferred(row_count) => {
+                let body_rows = self
+                    .pending_rows
+                    .iter()
+                    .filter(|row| row.kind == RowKind::Body);
+                body_rows.count() >= row_count
+            }
+        }
+    }
+
+    /// Decides the layout of the table from the pending rows, and writes them.
+    fn write_pending_rows(&mut self) -> io::Result<()> {
+        let mut table = Table {
+            rows: std::mem::take(&mut self.pending_rows),
+            ..self.table.clone()
+        };
+        let column_count = match &self.column_widths {
+            ColumnWidths::Fixed(widths) => widths.len(),
+            ColumnWidths::Inferred(_) => table.column_count(),
+        };
+        for row in table.rows.iter_mut() {
+            *row = fit_row(row, column_count);
+        }
+
+        let is_mirrored = table.direction == Direction::RightToLeft;
+        let mut original_table = None;
+        if is_mirrored {
+            if table.row_styler.is_some() {
+                original_table = Some(table.clone());
+            }
+            table.mirror(column_count);
+        }
+
+        let rows: Vec<Row> = table
+            .rows
+            .iter()
+            .map(|row| table.prepare_row(row, column_count))
+            .collect();
+        let original_rows: Vec<Option<Row>> = match original_table.as_mut() {
+            Some(original_table) => std::mem::take(&mut original_table.rows)
+                .iter()
+                .map(|row| Some(original_table.prepare_row(row, column_count)))
+                .collect(),
+            None => vec![None; rows.len()],
+        };
+        let decimal_widths = Table::calculate_decimal_widths(&rows);
+        let column_widths = match &self.column_widths {
+            ColumnWidths::Fixed(widths) => {
+                let mut widths: Vec<usize> = widths
+                    .iter()
+                    .map(|width| (*width).max(crate::MIN_COLUMN_WIDTH))
+                    .collect();
+                if is_mirrored {
+                    widths.reverse();
+                }
+                widths
+            }
+            ColumnWidths::Inferred(_) => {
+                let mut widths = table.calculate_column_widths(&rows, &decimal_widths);
+                table.fit_column_widths(&mut widths);
+                widths
+            }
+        };
+
+        table.rows.clear();
+        self.table = table;
+        self.layout = Some(Layout {
+            column_count,
+            column_widths,
+            decimal_widths,
+            is_mirrored,
+            original_table,
+        });
+        for (row, original_row) in rows.into_iter().zip(original_rows) {
+            self.write_prepared_row(row, original_row)?;
+        }
+        Ok(())
+    }
+
+    /// Writes a row, once the layout of the table is known.
+    fn write_row(&mut self, row: Row) -> io::Result<()> {
+        let Some(layout) = self.layout.as_ref() else {
+            return Ok(());
+        };
+        let mut row = fit_row(&row, layout.column_count);
+        let original_row = layout
+            .original_table
+            .as_ref()
+            .map(|table| table.prepare_row(&row, layout.column_count));
+        if layout.is_mirrored {
+            crate::mirror_row(&mut row, layout.column_count);
+        }
+        let row = self.table.prepare_row(&row, layout.column_count);
+        self.write_prepared_row(row, original_row)
+    }
+
+    /// Writes a row already prepared for rendering, along with its top border.
+    ///
+    /// The row styler sees the original row instead, if it differs (like before mirroring).
+    fn write_prepared_row(&mut self, row: Row, original_row: Option<Row>) -> io::Result<()> {
+        let Some(layout) = self.layout.as_ref() else {
+            return Ok(());
+        };
+
+        self.table.update_column_stats(&mut self.column_stats, &row);
+        let row_style = self.table.row_style(
+            self.row_count,
+            self.body_row_count,
+            original_row.as_ref().unwrap_or(&row),
+        );
+        let cell_styles = self
+            .table
+            .row_cell_styles(&row, row_style, &self.column_stats);
+
+        let mut rendered = String::new();
+        self.table.render_row(
+            &mut rendered,
+            &row,
+            self.last_row.as_ref(),
+            &layout.column_widths,
+            &layout.decimal_widths,
+            &cell_styles,
+        );
+        rendered.push('\n');
+        self.writer.write_all(rendered.as_bytes())?;
+
+        self.row_count += 1;
+        if row.kind == RowKind::Body {
+            self.body_row_count += 1;
+        }
+        self.last_row = Some(row);
+        Ok(())
+    }
+
+    /// Writes the remaining rows and the bottom border of the table, unless it is already finished.
+    fn finish_table(&mut self) -> io::Result<()> {
+        if self.is_finished {
+            return Ok(());
+        }
+        self.is_finished = true;
+
+        if self.layout.is_none() {
+            self.write_pending_rows()?;
+        }
+        if let Some((layout, last_row)) = self.layout.as_ref().zip(self.last_row.as_ref()) {
+            if self.table.has_bottom_border {
+                let mut rendered = String::new();
+                self.table
+                    .render_bottom_border(&mut rendered, &layout.column_widths, last_row);
+                rendered.push('\n');
+                self.writer.write_all(rendered.as_bytes())?;
+            }
+        }
+        self.writer.flush()
+    }
+}
+
+impl<W: Write> Drop for StreamingTable<W> {
+    fn drop(&mut self) {
+        // Errors cannot be reported when dropping, `StreamingTable::finish` should be used to handle them.
+        let _ = self.finish_table();
+    }
+}
+
+/// Discards the cells of a row beyond the given number of columns.
+fn fit_row(row: &Row, column_count: usize) -> Row {
+    let spanned_columns: usize = row.cells.iter().map(|cell| cell.column_span).sum();
+    if spanned_columns <= column_count {
+        return row.clone();
+    }
+    let columns: Vec<usize> = (0..column_count).collect();
+    column::project_row(row, &columns)
+}
@@ -203,4 +203,61 @@ impl Style {
             horizontal: ' ',
         }
     }
+
+    /// Returns the horizontally mirrored version of this style, used for right-to-left tables.
+    ///
+    /// The left and right characters are swapped, and mirrored themselves (`╭` becoming `╮`, for instance).
+    pub fn mirrored(&self) -> Style {
+        Style {
+            top_left_corner: mirror_char(self.top_right_corner),
+            top_right_corner: mirror_char(self.top_left_corner),
+            bottom_left_corner: mirror_char(self.bottom_right_corner),
+            bottom_right_corner: mirror_char(self.bottom_left_corner),
+            outer_left_vertical: mirror_char(self.outer_right_vertical),
+            outer_right_vertical: mirror_char(self.outer_left_vertical),
+            outer_bottom_horizontal: mirror_char(self.outer_bottom_horizontal),
+            outer_top_horizontal: mirror_char(self.outer_top_horizontal),
+            intersection: mirror_char(self.intersection),
+            vertical: mirror_char(self.vertical),
+            horizontal: mirror_char(self.horizontal),
+        }
+    }
+}
+
+/// Pairs of characters that are horizontal mirror images of each other.
+const MIRRORED_PAIRS: &[(char, char)] = &[
+    ('┌', '┐'),
+    ('└', '┘'),
+    ('├', '┤'),
+    ('┏', '┓'),
+    ('┗', '┛'),
+    ('┣', '┫'),
+    ('╔', '╗'),
+    ('╚', '╝'),
+    ('╠', '╣'),
+    ('╭', '╮'),
+    ('╰', '╯'),
+    ('╒', '╕'),
+    ('╘', '╛'),
+    ('╞', '╡'),
+    ('╓', '╖'),
+    ('╙', '╜'),
+    ('╟', '╢'),
+    ('/', '\\'),
+    ('(', ')'),
+    ('[', ']'),
+    ('{', '}'),
+    ('<', '>'),
+];
+
+/// Returns the horizontal mirror image of the given character, or the character itself if it is symmetric (or unknown).
+fn mirror_char(c: char) -> char {
+    MIRRORED_PAIRS
+        .iter()
+        .find_map(|&(left, right)| match c {
+            _ if c == left => Some(right),
+            _ if c == right => Some(left),
+            _ => None,
+        })
+        .unwrap_or(c)
 }
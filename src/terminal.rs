@@ -0,0 +1,21 @@
+use terminal_size::{Height, Width};
+
+/// Returns the width of the terminal attached to the standard output, if any.
+///
+/// Falls back to the `COLUMNS` environment variable when the standard output is not a terminal.
+pub(crate) fn terminal_width() -> Option<usize> {
+    match terminal_size::terminal_size() {
+        Some((Width(width), _)) => Some(width.into()),
+        None => std::env::var("COLUMNS").ok()?.parse().ok(),
+    }
+}
+
+/// Returns the height of the terminal attached to the standard output, if any.
+///
+/// Falls back to the `LINES` environment variable when the standard output is not a terminal.
+pub(crate) fn terminal_height() -> Option<usize> {
+    match terminal_size::terminal_size() {
+        Some((_, Height(height))) => Some(height.into()),
+        None => std::env::var("LINES").ok()?.parse().ok(),
+    }
+}
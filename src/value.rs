@@ -0,0 +1,411 @@
+use std::fmt;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// A typed value held by a cell.
+///
+/// Typed values are formatted when rendering, using the formatter of their column.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Value {
+    /// An integer.
+    Integer(i64),
+    /// A floating-point number.
+    Float(f64),
+    /// A fixed-point decimal number, made of a mantissa and a scale (the number of fractional digits).
+    ///
+    /// For instance, `Decimal(12345, 2)` represents `123.45`.
+    Decimal(i128, u32),
+    /// A boolean.
+    Bool(bool),
+    /// A duration.
+    Duration(Duration),
+    /// A point in time.
+    Timestamp(SystemTime),
+    /// A size, in bytes.
+    Bytes(u64),
+    /// The absence of a value.
+    Null,
+}
+
+impl Value {
+    /// Returns the numeric representation of the value, if it has one.
+    ///
+    /// Durations are represented in seconds, and timestamps in seconds since the UNIX epoch.
+    pub fn as_f64(&self) -> Option<f64> {
+        match *self {
+            Value::Integer(value) => Some(value as f64),
+            Value::Float(value) => Some(value),
+            Value::Decimal(mantissa, scale) => Some(mantissa as f64 / 10f64.powi(scale as i32)),
+            Value::Bool(_) | Value::Null => None,
+            Value::Duration(duration) => Some(duration.as_secs_f64()),
+            Value::Timestamp(timestamp) => Some(match timestamp.duration_since(UNIX_EPOCH) {
+                Ok(duration) => duration.as_secs_f64(),
+                Err(err) => -err.duration().as_secs_f64(),
+            }),
+            Value::Bytes(value) => Some(value as f64),
+        }
+    }
+
+    /// Adds two values of compatible types, returning `None` if they cannot be added (or on overflow).
+    ///
+    /// Integers and decimals are promoted to decimals, and integers or decimals are promoted to floats, as needed.
+    /// Nulls are ignored.
+    pub(crate) fn checked_add(self, other: Value) -> Option<Value> {
+        match (self, other) {
+            (value, Value::Null) | (Value::Null, value) => Some(value),
+            (Value::Integer(a), Value::Integer(b)) => a.checked_add(b).map(Value::Integer),
+            (Value::Decimal(a, a_scale), Value::Decimal(b, b_scale)) => {
+                let scale = a_scale.max(b_scale);
+                let sum = rescale(a, a_scale, scale)?.checked_add(rescale(b, b_scale, scale)?)?;
+                Some(Value::Decimal(sum, scale))
+            }
+            (Value::Integer(a), Value::Decimal(..)) => {
+                Value::Decimal(a.into(), 0).checked_add(other)
+            }
+            (Value::Decimal(..), Value::Integer(b)) => {
+                self.checked_add(Value::Decimal(b.into(), 0))
+            }
+            (Value::Float(a), Value::Float(b)) => Some(Value::Float(a + b)),
+            (Value::Float(a), Value::Integer(_) | Value::Decimal(..)) => {
+                Some(Value::Float(a + other.as_f64()?))
+            }
+            (Value::Integer(_) | Value::Decimal(..), Value::Float(b)) => {
+                Some(Value::Float(self.as_f64()? + b))
+            }
+            (Value::Duration(a), Value::Duration(b)) => a.checked_add(b).map(Value::Duration),
+            (Value::Bytes(a), Value::Bytes(b)) => a.checked_add(b).map(Value::Bytes),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for Value {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Value::Integer(value) => write!(f, "{value}"),
+            Value::Float(value) => write!(f, "{value}"),
+            Value::Decimal(mantissa, scale) => f.write_str(&format_decimal(mantissa, scale)),
+            Value::Bool(value) => write!(f, "{value}"),
+            Value::Duration(duration) => f.write_str(&format_duration(duration)),
+            Value::Timestamp(timestamp) => f.write_str(&format_timestamp(timestamp)),
+            Value::Bytes(value) => write!(f, "{value} B"),
+            Value::Null => Ok(()),
+        }
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Integer(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Integer(value.into())
+    }
+}
+
+impl From<u32> for Value {
+    fn from(value: u32) -> Self {
+        Value::Integer(value.into())
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Value::Float(value.into())
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<Duration> for Value {
+    fn from(value: Duration) -> Self {
+        Value::Duration(value)
+    }
+}
+
+impl From<SystemTime> for Value {
+    fn from(value: SystemTime) -> Self {
+        Value::Timestamp(value)
+    }
+}
+
+impl<T: Into<Value>> From<Option<T>> for Value {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(Value::Null, Into::into)
+    }
+}
+
+/// The formatting options for the typed values of a column.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValueFormatter {
+    /// The character inserted between groups of thousands, if any.
+    pub thousands_separator: Option<char>,
+    /// The number of fractional digits shown for numbers, if fixed.
+    pub precision: Option<usize>,
+    /// Whether byte sizes should be shown with binary units (like `1.5 KiB`).
+    pub has_human_readable_bytes: bool,
+    /// Whether timestamps should be shown relative to the current time (like `5 minutes ago`).
+    pub has_relative_times: bool,
+    /// The text shown for `true` and `false` booleans.
+    pub bool_display: (String, String),
+    /// The text shown for null values.
+    pub null_display: String,
+}
+
+impl Default for ValueFormatter {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ValueFormatter {
+    /// Creates a new formatter, with default settings.
+    pub fn new() -> Self {
+        Self {
+            thousands_separator: None,
+            precision: None,
+            has_human_readable_bytes: false,
+            has_relative_times: false,
+            bool_display: (String::from("true"), String::from("false")),
+            null_display: String::new(),
+        }
+    }
+
+    /// Sets the character inserted between groups of thousands.
+    pub fn with_thousands_separator(mut self, separator: char) -> Self {
+        self.thousands_separator = Some(separator);
+        self
+    }
+
+    /// Sets the number of fractional digits shown for numbers.
+    pub fn with_precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+        self
+    }
+
+    /// Shows byte sizes with binary units.
+    pub fn with_human_readable_bytes(mut self) -> Self {
+        self.has_human_readable_bytes = true;
+        self
+    }
+
+    /// Shows timestamps relative to the current time.
+    pub fn with_relative_times(mut self) -> Self {
+        self.has_relative_times = true;
+        self
+    }
+
+    /// Sets the text shown for `true` and `false` booleans.
+    pub fn with_bool_display(
+        mut self,
+        when_true: impl ToString,
+        when_false: impl ToString,
+    ) -> Self {
+        self.bool_display = (when_true.to_string(), when_false.to_string());
+        self
+    }
+
+    /// Shows booleans as check marks (`✓`) and crosses (`✗`).
+    pub fn with_check_marks(self) -> Self {
+        self.with_bool_display('✓', '✗')
+    }
+
+    /// Sets the text shown for null values.
+    pub fn with_null_display(mut self, null_display: impl ToString) -> Self {
+        self.null_display = null_display.to_string();
+        self
+    }
+
+    /// Formats the given value.
+    pub fn format(&self, value: &Value) -> String {
+        match *value {
+            Value::Integer(value) => self.format_number(&value.to_string()),
+            Value::Float(value) => match self.precision {
+                Some(precision) => self.format_number(&format!("{value:.precision$}")),
+                None => self.format_number(&value.to_string()),
+            },
+            Value::Decimal(mantissa, scale) => {
+                // Decimals too large to be rescaled to the precision are formatted with their own scale.
+                let (mantissa, scale) = self
+                    .precision
+                    .and_then(|precision| u32::try_from(precision).ok())
+                    .and_then(|precision| Some((rescale(mantissa, scale, precision)?, precision)))
+                    .unwrap_or((mantissa, scale));
+                self.format_number(&format_decimal(mantissa, scale))
+            }
+            Value::Bool(true) => self.bool_display.0.clone(),
+            Value::Bool(false) => self.bool_display.1.clone(),
+            Value::Duration(duration) => format_duration(duration),
+            Value::Timestamp(timestamp) if self.has_relative_times => {
+                format_relative_time(timestamp, SystemTime::now())
+            }
+            Value::Timestamp(timestamp) => format_timestamp(timestamp),
+            Value::Bytes(value) if self.has_human_readable_bytes => {
+                const UNITS: [&str; 7] = ["B", "KiB", "MiB", "GiB", "TiB", "PiB", "EiB"];
+                let mut size = value as f64;
+                let mut unit = 0;
+                while size >= 1024.0 && unit < UNITS.len() - 1 {
+                    size /= 1024.0;
+                    unit += 1;
+                }
+                if unit == 0 {
+                    format!("{} {}", self.format_number(&value.to_string()), UNITS[0])
+                } else {
+                    let precision = self.precision.unwrap_or(1);
+                    let size = self.format_number(&format!("{size:.precision$}"));
+                    format!("{} {}", size, UNITS[unit])
+                }
+            }
+            Value::Bytes(value) => format!("{} B", self.format_number(&value.to_string())),
+            Value::Null => self.null_display.clone(),
+        }
+    }
+
+    /// Inserts the thousands separator within the integral part of the given formatted number.
+    fn format_number(&self, number: &str) -> String {
+        let Some(separator) = self.thousands_separator else {
+            return number.to_string();
+        };
+        let (sign, number) = match number.strip_prefix('-') {
+            Some(number) => ("-", number),
+            None => ("", number),
+        };
+        let (integral, fractional) = number.split_at(number.find('.').unwrap_or(number.len()));
+        let mut formatted = String::from(sign);
+        for (index, digit) in integral.chars().enumerate() {
+            if index > 0 && (integral.len() - index) % 3 == 0 {
+                formatted.push(separator);
+            }
+            formatted.push(digit);
+        }
+        formatted.push_str(fractional);
+        formatted
+    }
+}
+
+/// Changes the scale of a decimal mantissa, rounding half away from zero.
+///
+/// Returns `None` if the rescaled mantissa overflows.
+fn rescale(mantissa: i128, scale: u32, new_scale: u32) -> Option<i128> {
+    if new_scale >= scale {
+        return 10i128
+            .checked_pow(new_scale - scale)
+            .and_then(|factor| mantissa.checked_mul(factor));
+    }
+    let Some(divisor) = 10u128.checked_pow(scale - new_scale) else {
+        // The divisor exceeds any mantissa, which rounds to zero.
+        return Some(0);
+    };
+    let quotient = mantissa.unsigned_abs() / divisor;
+    let remainder = mantissa.unsigned_abs() % divisor;
+    let rounded = quotient + u128::from(remainder >= divisor - remainder);
+    // The divisor being at least 10, the rounded quotient always fits.
+    let rounded = rounded as i128;
+    Some(if mantissa < 0 { -rounded } else { rounded })
+}
+
+/// Formats a decimal number from its mantissa and scale.
+fn format_decimal(mantissa: i128, scale: u32) -> String {
+    let digits = mantissa.unsigned_abs().to_string();
+    let sign = if mantissa < 0 { "-" } else { "" };
+    if scale == 0 {
+        return format!("{sign}{digits}");
+    }
+    let digits = format!("{digits:0>width$}", width = scale as usize + 1);
+    let (integral, fractional) = digits.split_at(digits.len() - scale as usize);
+    format!("{sign}{integral}.{fractional}")
+}
+
+/// Formats a duration using its most significant units (like `1h 2m 3s`, or `250ms`).
+fn format_duration(duration: Duration) -> String {
+    let seconds = duration.as_secs();
+    if seconds == 0 {
+        return format!("{}ms", duration.as_millis());
+    }
+    let (days, hours, minutes, seconds) = (
+        seconds / 86400,
+        seconds / 3600 % 24,
+        seconds / 60 % 60,
+        seconds % 60,
+    );
+    let parts: Vec<String> = [(days, "d"), (hours, "h"), (minutes, "m"), (seconds, "s")]
+        .into_iter()
+        .filter(|(amount, _)| *amount != 0)
+        .map(|(amount, unit)| format!("{amount}{unit}"))
+        .collect();
+    parts.join(" ")
+}
+
+/// Formats a timestamp as an RFC 3339 date and time, in UTC (like `2024-06-01T12:30:00Z`).
+fn format_timestamp(timestamp: SystemTime) -> String {
+    let seconds = match timestamp.duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(err) => -(err.duration().as_secs_f64().ceil() as i64),
+    };
+    let (days, seconds) = (seconds.div_euclid(86400), seconds.rem_euclid(86400));
+
+    // Converts the number of days since the epoch to a civil date (from Howard Hinnant's algorithm).
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days.rem_euclid(146097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+    let month = if month_index < 10 {
+        month_index + 3
+    } else {
+        month_index - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        seconds / 3600,
+        seconds / 60 % 60,
+        seconds % 60,
+    )
+}
+
+/// Formats a timestamp relative to `now` (like `5 minutes ago`, or `in 2 days`).
+fn format_relative_time(timestamp: SystemTime, now: SystemTime) -> String {
+    let (duration, is_future) = match now.duration_since(timestamp) {
+        Ok(duration) => (duration, false),
+        Err(err) => (err.duration(), true),
+    };
+    let seconds = duration.as_secs();
+    if seconds < 60 {
+        return String::from("just now");
+    }
+    let (amount, unit) = [
+        (365 * 86400, "year"),
+        (30 * 86400, "month"),
+        (7 * 86400, "week"),
+        (86400, "day"),
+        (3600, "hour"),
+        (60, "minute"),
+    ]
+    .into_iter()
+    .find(|(unit_seconds, _)| seconds >= *unit_seconds)
+    .map(|(unit_seconds, unit)| (seconds / unit_seconds, unit))
+    .unwrap_or((seconds / 60, "minute"));
+    let plural = if amount == 1 { "" } else { "s" };
+    if is_future {
+        format!("in {amount} {unit}{plural}")
+    } else {
+        format!("{amount} {unit}{plural} ago")
+    }
+}